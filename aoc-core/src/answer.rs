@@ -0,0 +1,148 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+
+use crate::utils::Grid;
+
+/// The answer to one part of a puzzle.
+///
+/// Answers compare by value, so `Int(7)` equals the parsed `"7"` but not `Str("7")`. Use
+/// [`Answer::parse`] to turn an expected answer back into an `Answer`. Parsing can't restore the
+/// type of text like `"0123"`, so answers stored as text are compared by their `Display` output.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    /// An integer exceeding `i128`, only ever created for such values
+    BigInt(BigInt),
+    Str(String),
+    /// A picture, e.g. letters drawn with `#`, without trailing whitespace or empty rows
+    Grid(Vec<String>),
+    /// The part has no answer for this input
+    None,
+}
+
+impl Answer {
+    /// Renders the rows of a picture.
+    pub fn grid<S: AsRef<str>>(rows: impl IntoIterator<Item = S>) -> Self {
+        let mut rows = rows
+            .into_iter()
+            .map(|row| row.as_ref().trim_end().to_owned())
+            .collect::<Vec<_>>();
+        while rows.last().is_some_and(String::is_empty) {
+            rows.pop();
+        }
+        Answer::Grid(rows)
+    }
+
+    /// Renders the cells of a grid with `f`.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> char) -> Self {
+        Self::grid((0..grid.height()).map(|y| {
+            (0..grid.width())
+                .map(|x| f(&grid[(x, y)]))
+                .collect::<String>()
+        }))
+    }
+
+    /// The inverse of `Display`: integers become `Int` or `BigInt`, multiple lines a `Grid`,
+    /// nothing `None` and everything else `Str`.
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim_end();
+        if s.trim().is_empty() {
+            return Answer::None;
+        }
+        if s.contains('\n') {
+            return Self::grid(s.lines());
+        }
+        if let Ok(n) = s.parse::<i128>() {
+            return Answer::Int(n);
+        }
+        match s.parse::<BigInt>() {
+            Ok(n) => Answer::BigInt(n),
+            Err(_) => Answer::Str(s.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i128::try_from(n) {
+                    Ok(n) => Answer::Int(n),
+                    Err(_) => Answer::BigInt(BigInt::from(n)),
+                }
+            }
+        })*
+    };
+}
+
+from_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i128::try_from(&n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_inverts_display() {
+        let answers = [
+            Answer::from(42u64),
+            Answer::from(-3i32),
+            Answer::from(u128::MAX),
+            Answer::from("1,2,3"),
+            Answer::grid(["#..#", "####  ", ""]),
+            Answer::None,
+        ];
+        for answer in answers {
+            assert_eq!(Answer::parse(&answer.to_string()), answer);
+        }
+        assert!(matches!(Answer::from(u128::MAX), Answer::BigInt(_)));
+    }
+
+    #[test]
+    fn comparison_is_type_aware() {
+        assert_eq!(Answer::parse("007"), Answer::from(7));
+        assert_ne!(Answer::parse("7"), Answer::from("7"));
+        assert_eq!(Answer::from(BigInt::from(12)), Answer::Int(12));
+        assert_eq!(Answer::parse("#.\n.#  \n"), Answer::grid(["#.", ".#"]));
+    }
+
+    #[test]
+    fn renders_grids() {
+        let grid = Grid::try_parse("#.\n.#", |c| Some(c == '#')).unwrap();
+        let answer = Answer::from_grid(&grid, |&b| if b { '#' } else { '.' });
+        assert_eq!(answer.to_string(), "#.\n.#");
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use crate::Part;
+
+/// A separately measured step of solving a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub fn to_string(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::A) => "a",
+            Stage::Solve(Part::B) => "b",
+        }
+    }
+}
+
+/// How often every stage is executed when benchmarking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs before the measurement starts, their timings are discarded
+    pub warmup: usize,
+    /// Measured runs
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 20,
+        }
+    }
+}
+
+/// The timings of all measured runs of one stage.
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub stage: Stage,
+    pub samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub fn stats(&self) -> Stats {
+        Stats::from_samples(&self.samples)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` according to `config` and collects the measured durations.
+pub fn measure<F>(config: BenchConfig, mut f: F) -> Vec<Duration>
+where
+    F: FnMut(),
+{
+    for _ in 0..config.warmup {
+        f();
+    }
+
+    (0..config.samples)
+        .map(|_| {
+            let timer = std::time::Instant::now();
+            f();
+            timer.elapsed()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn measure_runs_warmup() {
+        let mut counter = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            samples: 5,
+        };
+        let samples = measure(config, || counter += 1);
+
+        assert_eq!(samples.len(), 5);
+        assert_eq!(counter, 7);
+    }
+}
@@ -0,0 +1,246 @@
+use std::any::Any;
+
+use crate::Part;
+
+/// The position of an error within the puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// Byte offset into the puzzle input
+    pub offset: usize,
+    /// One based line number
+    pub line: usize,
+    /// One based column, counted in characters
+    pub column: usize,
+    /// The complete line containing the offending input
+    pub snippet: String,
+}
+
+impl Location {
+    pub fn new(content: &str, offset: usize) -> Self {
+        let offset = offset.min(content.len());
+        let line_start = content[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = content[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or(content.len());
+
+        Self {
+            offset,
+            line: content[..line_start].matches('\n').count() + 1,
+            column: content[line_start..offset].chars().count() + 1,
+            snippet: content[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The puzzle input could not be read
+    Input,
+    /// The puzzle input does not have the expected format
+    Parse,
+    /// The parsed input does not admit a solution
+    Solve,
+    /// A puzzle parameter is unknown or has an invalid value
+    Param,
+    /// There is no solution registered for the requested day or part
+    NotImplemented,
+    /// Solving a part took longer than allowed
+    Timeout,
+    /// The solution panicked
+    Panic,
+    /// The puzzle server could not be reached, rejected a request or a request was refused
+    Remote,
+}
+
+/// An error raised while loading, parsing or solving a puzzle.
+///
+/// Solutions only describe what went wrong, the runner attaches the day and part.
+#[derive(Clone, Debug)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub day: Option<u32>,
+    pub part: Option<Part>,
+    pub location: Option<Location>,
+}
+
+impl AocError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            day: None,
+            part: None,
+            location: None,
+        }
+    }
+
+    pub fn input(path: &str, err: std::io::Error) -> Self {
+        Self::new(
+            ErrorKind::Input,
+            format!("unable to read `{}`: {}", path, err),
+        )
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse, message)
+    }
+
+    /// A parse error pointing at `fragment`, which has to be a subslice of `content`.
+    pub fn parse_at(content: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = content.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= content.len())
+            .unwrap_or(content.len());
+
+        Self::parse(message).with_location(Location::new(content, offset))
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve, message)
+    }
+
+    /// The error of a caught panic, the payload is a `&str` or a `String` unless the panic was
+    /// raised with `panic_any`.
+    pub fn panicked(payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_owned());
+        Self::new(ErrorKind::Panic, format!("panicked: {}", message))
+    }
+
+    pub fn not_implemented(year: u32, day: u32) -> Self {
+        Self::new(
+            ErrorKind::NotImplemented,
+            format!("not implemented in {}", year),
+        )
+        .with_day(day)
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn with_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+}
+
+impl std::fmt::Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {} part {}: ", day, part.to_string())?,
+            (Some(day), None) => write!(f, "day {}: ", day)?,
+            (None, Some(part)) => write!(f, "part {}: ", part.to_string())?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)?;
+
+        if let Some(location) = &self.location {
+            let indent = location
+                .snippet
+                .chars()
+                .take(location.column - 1)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            write!(
+                f,
+                "\n  --> line {}, column {} (byte {})\n   | {}\n   | {}^",
+                location.line, location.column, location.offset, location.snippet, indent
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// Converts the result of a nom parser applied to the complete puzzle input.
+///
+/// Anything but whitespace left over after parsing is reported as an error, so truncated or
+/// malformed inputs do not go unnoticed.
+pub fn finish<'a, T>(content: &'a str, result: nom::IResult<&'a str, T>) -> Result<T, AocError> {
+    match result {
+        Ok((remainder, value)) if remainder.trim().is_empty() => Ok(value),
+        Ok((remainder, _)) => Err(AocError::parse_at(
+            content,
+            remainder,
+            "unexpected trailing input",
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(AocError::parse_at(
+            content,
+            e.input,
+            format!("unexpected input ({})", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse_at(
+            content,
+            &content[content.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// Parses `fragment`, a subslice of `content`, as a number.
+pub fn number<T: std::str::FromStr>(content: &str, fragment: &str) -> Result<T, AocError> {
+    let fragment = fragment.trim();
+    fragment.parse().map_err(|_| {
+        AocError::parse_at(
+            content,
+            fragment,
+            format!("expected a number, found `{}`", fragment),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_points_into_line() {
+        let content = "first\nsecond line\nthird";
+        let offset = content.find("line").unwrap();
+        let location = Location::new(content, offset);
+
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 8);
+        assert_eq!(location.snippet, "second line");
+    }
+
+    #[test]
+    fn finish_reports_trailing_input() {
+        let content = "12,34\nxy";
+        let result = nom::character::complete::u32::<_, nom::error::Error<&str>>(content);
+        let err = finish(content, result).unwrap_err();
+
+        assert_eq!(err.kind, ErrorKind::Parse);
+        assert_eq!(err.location.unwrap().offset, 2);
+    }
+
+    #[test]
+    fn display_contains_context() {
+        let content = "1 2\n3 x";
+        let err = number::<u32>(content, &content[6..7])
+            .unwrap_err()
+            .with_day(4)
+            .with_part(Part::B);
+
+        assert_eq!(
+            err.to_string(),
+            "day 4 part b: expected a number, found `x`\n  --> line 2, column 3 (byte 6)\n   | 3 x\n   |   ^"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Puzzle examples stored as `examples/dayNN/<name>.txt` in the crate of a year.
+//!
+//! An example file starts with a header listing the expected answers and the puzzle parameters,
+//! followed by the input:
+//!
+//! ```text
+//! ---
+//! a = 22
+//! b = 6,1
+//! size = 6
+//! ---
+//! 5,4
+//! 4,2
+//! ```
+//!
+//! The build script of the year calls [`build`] to generate one test per example and part, which
+//! `gen_examples!` includes into the crate.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, Params, Part, Solution};
+
+const DELIMITER: &str = "---";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example<'a> {
+    pub input: &'a str,
+    pub answers: Vec<(Part, &'a str)>,
+    pub params: Params,
+}
+
+impl<'a> Example<'a> {
+    pub fn parse(file: &'a str) -> Result<Self, String> {
+        let Some(rest) = file.strip_prefix(DELIMITER) else {
+            return Err(format!("expected a header starting with `{}`", DELIMITER));
+        };
+        let rest = rest
+            .trim_start_matches('\r')
+            .strip_prefix('\n')
+            .unwrap_or(rest);
+
+        let mut answers = Vec::new();
+        let mut params = Params::new();
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            offset += line.len();
+            let line = line.trim();
+            if line == DELIMITER {
+                return Ok(Self {
+                    input: &rest[offset..],
+                    answers,
+                    params,
+                });
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("expected `KEY = VALUE`, found `{}`", line));
+            };
+            match (key.trim(), value.trim()) {
+                ("a", answer) => answers.push((Part::A, answer)),
+                ("b", answer) => answers.push((Part::B, answer)),
+                (key, value) => params = params.with(key, value),
+            }
+        }
+
+        Err(format!("the header is not closed with `{}`", DELIMITER))
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&'a str> {
+        self.answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|&(_, answer)| answer)
+    }
+}
+
+/// Solves `part` of the example stored in `file` and compares it to the expected answer.
+pub fn check<S: Solution>(name: &str, file: &str, part: Part) {
+    let example = Example::parse(file).unwrap_or_else(|e| panic!("{}: {}", name, e));
+    let expected = example
+        .answer(part)
+        .unwrap_or_else(|| panic!("{}: no answer for part {}", name, part.to_string()));
+
+    let input =
+        S::parse_with(example.input, &example.params).unwrap_or_else(|e| panic!("{}: {}", name, e));
+    let result = match part {
+        Part::A => S::part_a(&input),
+        Part::B => S::part_b(&input),
+    }
+    .unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+    assert_eq!(result, Answer::parse(expected), "{}", name);
+}
+
+/// Generates the tests of all examples below `dir`, `has_day` tells which days are solved.
+pub fn generate(dir: &Path, has_day: impl Fn(u32) -> bool) -> Result<String, String> {
+    let mut out = String::new();
+    let Ok(days) = std::fs::read_dir(dir) else {
+        return Ok(out);
+    };
+
+    let mut days = days
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    days.sort();
+
+    for day_dir in days {
+        let name = day_dir.file_name().unwrap().to_string_lossy().into_owned();
+        let Some(day) = name
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<u32>().ok())
+        else {
+            return Err(format!("unexpected directory `{}`", day_dir.display()));
+        };
+        if !has_day(day) {
+            return Err(format!(
+                "there is no solution for the examples of day {}",
+                day
+            ));
+        }
+
+        let mut files = std::fs::read_dir(&day_dir)
+            .map_err(|e| e.to_string())?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect::<Vec<_>>();
+        files.sort();
+
+        // File names differing only in the characters replaced below would define a test twice
+        let mut stems = HashMap::<String, PathBuf>::new();
+        for file in files {
+            let content = std::fs::read_to_string(&file).map_err(|e| e.to_string())?;
+            let example =
+                Example::parse(&content).map_err(|e| format!("{}: {}", file.display(), e))?;
+            let stem = file
+                .file_stem()
+                .unwrap()
+                .to_string_lossy()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+                .to_lowercase();
+            if let Some(other) = stems.insert(stem.clone(), file.clone()) {
+                return Err(format!(
+                    "`{}` and `{}` both generate the tests `day{:0>2}_{}_*`",
+                    other.display(),
+                    file.display(),
+                    day,
+                    stem
+                ));
+            }
+
+            for (part, _) in &example.answers {
+                let part = part.to_string();
+                writeln!(
+                    out,
+                    "#[test]\nfn day{day:0>2}_{stem}_{part}() {{\n    \
+                     ::aoc_core::examples::check::<super::day{day:0>2}::Day{day:0>2}>(\n        \
+                     {name:?},\n        include_str!({path:?}),\n        \
+                     ::aoc_core::Part::{upper},\n    );\n}}\n",
+                    name = format!("{}/{}", name, file.file_name().unwrap().to_string_lossy()),
+                    path = file.display().to_string(),
+                    upper = part.to_uppercase(),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Entry point for the build script of a year, writes the tests for `examples/` to
+/// `$OUT_DIR/examples.rs`.
+pub fn build() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let dir = Path::new(&manifest_dir).join("examples");
+    let src = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let tests = generate(&dir, |day| src.join(format!("day{:0>2}.rs", day)).is_file())
+        .unwrap_or_else(|e| panic!("invalid examples: {}", e));
+    std::fs::write(Path::new(&out_dir).join("examples.rs"), tests).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_header() {
+        let example = Example::parse("---\na = 22\nb = 6,1\nsize = 6\n---\n5,4\n4,2\n").unwrap();
+        assert_eq!(example.input, "5,4\n4,2\n");
+        assert_eq!(example.answer(Part::A), Some("22"));
+        assert_eq!(example.answer(Part::B), Some("6,1"));
+        assert_eq!(example.params, Params::new().with("size", 6));
+
+        assert!(Example::parse("5,4\n").is_err());
+        assert!(Example::parse("---\na = 1\n5,4\n").is_err());
+    }
+
+    #[test]
+    fn generates_a_test_per_part() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        std::fs::create_dir(dir.join("day07")).unwrap();
+        std::fs::write(
+            dir.join("day07").join("Small One.txt"),
+            "---\na=1\nb=2\n---\nx",
+        )
+        .unwrap();
+
+        let tests = generate(dir, |day| day == 7).unwrap();
+        assert!(tests.contains("fn day07_small_one_a()"));
+        assert!(tests.contains("fn day07_small_one_b()"));
+        assert!(tests.contains("super::day07::Day07"));
+        assert!(generate(dir, |_| false).is_err());
+    }
+
+    #[test]
+    fn rejects_clashing_test_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        std::fs::create_dir(dir.join("day07")).unwrap();
+        for name in ["a-b.txt", "a_b.txt"] {
+            std::fs::write(dir.join("day07").join(name), "---\na=1\n---\nx").unwrap();
+        }
+
+        let err = generate(dir, |day| day == 7).unwrap_err();
+        assert!(err.contains("a-b.txt"), "{}", err);
+        assert!(err.contains("a_b.txt"), "{}", err);
+    }
+}
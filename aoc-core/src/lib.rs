@@ -0,0 +1,292 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bench::{measure, BenchConfig, Measurement, Stage};
+
+pub mod answer;
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod params;
+pub mod plugin;
+pub mod registry;
+pub mod utils;
+
+pub use answer::Answer;
+pub use error::{finish, number, AocError, ErrorKind};
+pub use params::Params;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::A, Part::B];
+
+    pub fn to_string(self) -> &'static str {
+        match self {
+            Part::A => "a",
+            Part::B => "b",
+        }
+    }
+}
+
+/// The outcome of solving one part of a day.
+#[derive(Clone, Debug)]
+pub struct SolveReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+}
+
+/// A step of solving a day, handed out by [`Solution::steps`] as soon as it is done.
+#[derive(Clone, Debug)]
+pub enum Step {
+    /// The input was parsed in this time
+    Parsed(Duration),
+    /// A part was solved or failed
+    Solved(Result<SolveReport, AocError>),
+}
+
+/// The outcome of solving a day.
+///
+/// The input is parsed once per run, the time it takes is reported separately from the parts.
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub parse_time: Duration,
+    pub parts: Vec<SolveReport>,
+}
+
+impl DayReport {
+    /// The time spent solving all parts, without parsing.
+    pub fn solve_time(&self) -> Duration {
+        self.parts.iter().map(|report| report.solve_time).sum()
+    }
+
+    /// The time spent on the whole day, including parsing.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.solve_time()
+    }
+
+    /// Collects the steps of solving a day, fails with the first error.
+    pub fn from_steps(
+        steps: impl FnOnce(&mut dyn FnMut(Step)) -> Result<(), AocError>,
+    ) -> Result<Self, AocError> {
+        let mut report = DayReport {
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        };
+        let mut failed = None;
+        steps(&mut |step| match step {
+            Step::Parsed(parse_time) => report.parse_time = parse_time,
+            Step::Solved(Ok(solved)) => report.parts.push(solved),
+            Step::Solved(Err(e)) => {
+                failed.get_or_insert(e);
+            }
+        })?;
+        match failed {
+            Some(e) => Err(e),
+            None => Ok(report),
+        }
+    }
+}
+
+pub trait Solution {
+    type Input<'a>;
+
+    /// The parts that are solved, the second part of the last day of a year doesn't exist.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError>;
+
+    /// Parses the input for the puzzle `params`, days with parameters override this and
+    /// implement `parse` with the defaults.
+    fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+        params.check(&[])?;
+        Self::parse(content)
+    }
+
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError>;
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError>;
+
+    /// Parses the input once and solves the `parts` in order, handing every step to `step` as
+    /// soon as it is done. Only a parse error is returned, a failing part doesn't stop the next.
+    fn steps(
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+        step: &mut dyn FnMut(Step),
+    ) -> Result<(), AocError> {
+        let timer = Instant::now();
+        let input = Self::parse_with(content, params)?;
+        step(Step::Parsed(timer.elapsed()));
+
+        for &part in parts {
+            let timer = Instant::now();
+            let answer = match part {
+                Part::A => Self::part_a(&input),
+                Part::B => Self::part_b(&input),
+            };
+            let solve_time = timer.elapsed();
+
+            step(Step::Solved(
+                answer
+                    .map(|answer| SolveReport {
+                        part,
+                        answer,
+                        solve_time,
+                    })
+                    .map_err(|e| e.with_part(part)),
+            ));
+        }
+        Ok(())
+    }
+
+    fn run(content: &str, parts: &[Part], params: &Params) -> Result<DayReport, AocError> {
+        DayReport::from_steps(|step| Self::steps(content, parts, params, step))
+    }
+
+    /// Measures parsing and each of the requested parts separately.
+    fn bench(
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+        config: BenchConfig,
+    ) -> Result<Vec<Measurement>, AocError> {
+        // Make sure every stage succeeds before timing it
+        Self::run(content, parts, params)?;
+
+        let mut measurements = vec![Measurement {
+            stage: Stage::Parse,
+            samples: measure(config, || {
+                let _ = black_box(Self::parse_with(black_box(content), params));
+            }),
+        }];
+
+        let input = Self::parse_with(content, params)?;
+        for &part in parts {
+            let samples = measure(config, || {
+                let _ = black_box(match part {
+                    Part::A => Self::part_a(black_box(&input)),
+                    Part::B => Self::part_b(black_box(&input)),
+                });
+            });
+            measurements.push(Measurement {
+                stage: Stage::Solve(part),
+                samples,
+            });
+        }
+
+        Ok(measurements)
+    }
+}
+
+/// Declares the day modules of a year and registers them in its `DAYS` list.
+///
+/// ```ignore
+/// gen_solve!(2023; (1, day01, Day01, "Trebuchet?!"), (2, day02, Day02, "Cube Conundrum"));
+/// ```
+#[macro_export]
+macro_rules! gen_solve {
+
+    ( $year:expr; $(($x:expr, $m:ident, $d:ident, $title:expr)),* ) => {
+
+        $(mod $m;)*
+
+        pub static DAYS: &[$crate::registry::DayEntry] = &[
+            $($crate::registry::DayEntry::new::<$m::$d>($year, $x, $title)),*
+        ];
+    };
+}
+
+/// Includes the tests generated from the example files by the build script of the year, see
+/// [`examples`].
+#[macro_export]
+macro_rules! gen_examples {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+/// Generates a test checking one part of a day against a puzzle example, optionally followed by
+/// the puzzle parameters of the example.
+///
+/// ```ignore
+/// gen_test!(a, Day18, EXAMPLE, "22", size = 6, bytes = 12);
+/// ```
+#[macro_export]
+macro_rules! gen_test {
+    (a, $d:ident, $input:expr, $output:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        #[cfg(test)]
+        #[test]
+        fn test_part_a() {
+            let params = $crate::Params::new()$(.with(stringify!($key), $value))*;
+            let input = <$d as $crate::Solution>::parse_with($input, &params).unwrap();
+            let output = $crate::Answer::parse(&$output.to_string());
+            let result = <$d as $crate::Solution>::part_a(&input).unwrap();
+            assert_eq!(result, output);
+        }
+    };
+    (b, $d:ident, $input:expr, $output:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        #[test]
+        #[cfg(test)]
+        fn test_part_b() {
+            let params = $crate::Params::new()$(.with(stringify!($key), $value))*;
+            let input = <$d as $crate::Solution>::parse_with($input, &params).unwrap();
+            let output = $crate::Answer::parse(&$output.to_string());
+            let result = <$d as $crate::Solution>::part_b(&input).unwrap();
+            assert_eq!(result, output);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input<'a> = Vec<u32>;
+
+        fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+            content.lines().map(|line| number(content, line)).collect()
+        }
+
+        fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            Ok(input.iter().product::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn run_reports_requested_parts() {
+        let report = Sum::run("2\n3\n4", &[Part::B], &Params::new()).unwrap();
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, Part::B);
+        assert_eq!(report.parts[0].answer, Answer::Int(24));
+
+        let report = Sum::run("2\n3\n4", &Part::ALL, &Params::new()).unwrap();
+        assert_eq!(report.total_time(), report.parse_time + report.solve_time());
+        let answers = report
+            .parts
+            .iter()
+            .map(|r| r.answer.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(answers, vec![Answer::Int(9), Answer::Int(24)]);
+    }
+
+    #[test]
+    fn run_reports_parse_errors() {
+        let err = Sum::run("2\nthree", &Part::ALL, &Params::new()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Parse);
+        assert_eq!(err.location.unwrap().line, 2);
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::{AocError, ErrorKind};
+
+/// Named values distinguishing the examples from the real puzzle, e.g. the size of a room.
+///
+/// Days taking parameters read them in `Solution::parse_with` into their own struct, every
+/// parameter that isn't given falls back to the value for the real input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.values.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Overrides the values of `self` with the ones of `other`.
+    pub fn merge(mut self, other: &Params) -> Self {
+        self.values
+            .extend(other.values.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self
+    }
+
+    /// All given parameters, ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Parses a `key=value` assignment as given on the command line.
+    pub fn parse_assignment(s: &str) -> Result<(String, String), AocError> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_owned(), value.trim().to_owned()))
+            }
+            _ => Err(AocError::new(
+                ErrorKind::Param,
+                format!("expected `KEY=VALUE`, found `{}`", s),
+            )),
+        }
+    }
+
+    /// Fails if a parameter is given that isn't one of `known`.
+    pub fn check(&self, known: &[&str]) -> Result<(), AocError> {
+        match self
+            .values
+            .keys()
+            .find(|key| !known.contains(&key.as_str()))
+        {
+            None => Ok(()),
+            Some(key) if known.is_empty() => Err(AocError::new(
+                ErrorKind::Param,
+                format!("unknown parameter `{}`, the day takes none", key),
+            )),
+            Some(key) => Err(AocError::new(
+                ErrorKind::Param,
+                format!(
+                    "unknown parameter `{}`, expected one of {}",
+                    key,
+                    known.join(", ")
+                ),
+            )),
+        }
+    }
+
+    /// The value of `key`, `default` if it isn't given.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, AocError> {
+        match self.values.get(key) {
+            None => Ok(default),
+            Some(value) => value.parse().map_err(|_| {
+                AocError::new(
+                    ErrorKind::Param,
+                    format!("invalid value `{}` for parameter `{}`", value, key),
+                )
+            }),
+        }
+    }
+}
+
+impl<K: Into<String>, V: ToString> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::new(), |params, (key, value)| params.with(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_and_defaults() {
+        let params = Params::new().with("size", 6);
+        assert_eq!(params.get("size", 70).unwrap(), 6);
+        assert_eq!(params.get("bytes", 1024).unwrap(), 1024);
+        assert!(params.check(&["size", "bytes"]).is_ok());
+
+        let err = params.check(&["width"]).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Param);
+        assert!(params.get::<bool>("size", false).is_err());
+
+        let merged = Params::new()
+            .with("size", 70)
+            .with("bytes", 1024)
+            .merge(&params);
+        assert_eq!(merged, Params::new().with("size", 6).with("bytes", 1024));
+    }
+
+    #[test]
+    fn assignments() {
+        assert_eq!(
+            Params::parse_assignment("size = 6").unwrap(),
+            ("size".to_owned(), "6".to_owned())
+        );
+        assert!(Params::parse_assignment("size").is_err());
+        assert!(Params::parse_assignment("=6").is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! A C ABI for solutions built as shared libraries, so the runner can load alternative solutions
+//! of a day without being rebuilt.
+//!
+//! A plugin is a `cdylib` crate whose `lib.rs` lists its days with [`gen_plugin!`]:
+//!
+//! ```ignore
+//! aoc_core::gen_plugin!("bitset"; (2024, 1, Day01, "Historian Hysteria"));
+//! ```
+//!
+//! The macro exports `aoc_plugin`, returning a [`PluginTable`] that describes the days of the
+//! plugin. Only `repr(C)` types cross the boundary: the input and the parameters are passed as
+//! UTF-8 strings and answers and error messages are returned as strings allocated by the plugin,
+//! which the runner hands back to the plugin's `free` function.
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::{Answer, AocError, DayReport, ErrorKind, Params, Part, Solution, SolveReport, Step};
+
+/// Version of the plugin interface, changed on every incompatible change of the types below
+pub const ABI_VERSION: u32 = 1;
+
+/// The name of the function returning the [`PluginTable`], NUL terminated for `dlsym`
+pub const SYMBOL: &[u8] = b"aoc_plugin\0";
+
+/// Solves one part of the input given as UTF-8 bytes, the parameters are NUL terminated
+/// `key=value` lines.
+pub type SolveFn =
+    extern "C" fn(input: *const u8, len: usize, params: *const c_char, part: u32) -> PluginResult;
+
+/// Frees a string returned by the plugin.
+pub type FreeFn = extern "C" fn(text: *mut c_char);
+
+/// The signature of the exported [`SYMBOL`].
+pub type EntryFn = extern "C" fn() -> *const PluginTable;
+
+/// The outcome of [`SolveFn`].
+#[repr(C)]
+pub struct PluginResult {
+    /// 0 on success, otherwise the code of the `ErrorKind` of the error
+    pub status: u32,
+    /// The answer or the error message, owned by the plugin
+    pub text: *mut c_char,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+/// One day solved by a plugin.
+#[repr(C)]
+pub struct PluginDay {
+    pub year: u32,
+    pub day: u32,
+    /// NUL terminated
+    pub title: *const c_char,
+    /// Bit 0 is set if part a is solved, bit 1 for part b
+    pub parts: u32,
+    pub solve: SolveFn,
+}
+
+// SAFETY: the pointers refer to string literals, which are immutable and live forever
+unsafe impl Sync for PluginDay {}
+
+/// Everything a plugin exports.
+#[repr(C)]
+pub struct PluginTable {
+    pub abi_version: u32,
+    /// The name of the variant, NUL terminated
+    pub name: *const c_char,
+    pub days: *const PluginDay,
+    pub len: usize,
+    pub free: FreeFn,
+}
+
+// SAFETY: see `PluginDay`
+unsafe impl Sync for PluginTable {}
+
+impl PluginDay {
+    /// Describes the day solved by `S`, `title` has to end with a NUL byte.
+    pub const fn new<S: Solution>(year: u32, day: u32, title: &'static str) -> Self {
+        let mut parts = 0;
+        let mut i = 0;
+        while i < S::PARTS.len() {
+            parts |= part_bit(S::PARTS[i]);
+            i += 1;
+        }
+        Self {
+            year,
+            day,
+            title: title.as_ptr() as *const c_char,
+            parts,
+            solve: solve::<S>,
+        }
+    }
+}
+
+impl PluginTable {
+    /// Describes a plugin, `name` has to end with a NUL byte.
+    pub const fn new(name: &'static str, days: &'static [PluginDay]) -> Self {
+        Self {
+            abi_version: ABI_VERSION,
+            name: name.as_ptr() as *const c_char,
+            days: days.as_ptr(),
+            len: days.len(),
+            free,
+        }
+    }
+}
+
+const fn part_bit(part: Part) -> u32 {
+    match part {
+        Part::A => 1,
+        Part::B => 2,
+    }
+}
+
+fn kind_code(kind: &ErrorKind) -> u32 {
+    match kind {
+        ErrorKind::Input => 1,
+        ErrorKind::Parse => 2,
+        ErrorKind::Solve => 3,
+        ErrorKind::Param => 4,
+        ErrorKind::NotImplemented => 5,
+        ErrorKind::Timeout => 6,
+        ErrorKind::Panic => 7,
+        ErrorKind::Remote => 8,
+    }
+}
+
+fn kind_from_code(code: u32) -> ErrorKind {
+    match code {
+        1 => ErrorKind::Input,
+        2 => ErrorKind::Parse,
+        4 => ErrorKind::Param,
+        5 => ErrorKind::NotImplemented,
+        6 => ErrorKind::Timeout,
+        7 => ErrorKind::Panic,
+        8 => ErrorKind::Remote,
+        _ => ErrorKind::Solve,
+    }
+}
+
+fn to_c_string(s: String) -> *mut c_char {
+    // Strings with NUL bytes can't cross the boundary, so they are cut at the first one
+    let s = match s.find('\0') {
+        Some(end) => s[..end].to_owned(),
+        None => s,
+    };
+    CString::new(s).unwrap_or_default().into_raw()
+}
+
+extern "C" fn free(text: *mut c_char) {
+    if !text.is_null() {
+        // SAFETY: `text` was created by `to_c_string` of this very library
+        drop(unsafe { CString::from_raw(text) });
+    }
+}
+
+/// The [`SolveFn`] of `S`, a panic must not unwind into the runner so it is reported as an error.
+extern "C" fn solve<S: Solution>(
+    input: *const u8,
+    len: usize,
+    params: *const c_char,
+    part: u32,
+) -> PluginResult {
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        // SAFETY: the runner passes a valid string of `len` bytes and a NUL terminated one
+        let (content, params) = unsafe {
+            (
+                std::str::from_utf8(std::slice::from_raw_parts(input, len)),
+                CStr::from_ptr(params).to_str(),
+            )
+        };
+        let content = content.map_err(|_| AocError::parse("the input is not UTF-8"))?;
+        let params = params
+            .map_err(|_| AocError::new(ErrorKind::Param, "the parameters are not UTF-8"))?
+            .lines()
+            .map(Params::parse_assignment)
+            .collect::<Result<Params, _>>()?;
+        let part = match part {
+            1 => Part::A,
+            2 => Part::B,
+            _ => return Err(AocError::new(ErrorKind::NotImplemented, "unknown part")),
+        };
+        S::run(content, &[part], &params)
+    }));
+
+    match result {
+        Ok(Ok(report)) => PluginResult {
+            status: 0,
+            text: to_c_string(report.parts[0].answer.to_string()),
+            parse_ns: report.parse_time.as_nanos() as u64,
+            solve_ns: report.parts[0].solve_time.as_nanos() as u64,
+        },
+        Ok(Err(e)) => PluginResult {
+            status: kind_code(&e.kind),
+            text: to_c_string(e.message),
+            parse_ns: 0,
+            solve_ns: 0,
+        },
+        Err(payload) => PluginResult {
+            status: kind_code(&ErrorKind::Panic),
+            text: to_c_string(AocError::panicked(&*payload).message),
+            parse_ns: 0,
+            solve_ns: 0,
+        },
+    }
+}
+
+/// A day of a loaded plugin, the runner's side of [`PluginDay`].
+#[derive(Clone, Debug)]
+pub struct Variant {
+    /// The name of the plugin
+    pub name: String,
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub parts: Vec<Part>,
+    solve: SolveFn,
+    free: FreeFn,
+}
+
+impl Variant {
+    /// Reads the days of a plugin.
+    ///
+    /// # Safety
+    ///
+    /// `table` has to point to a `PluginTable` created by [`gen_plugin!`], and the library it
+    /// belongs to must stay loaded as long as the variants are used.
+    pub unsafe fn from_table(table: *const PluginTable) -> Result<Vec<Variant>, AocError> {
+        let table = unsafe { table.as_ref() }
+            .ok_or_else(|| AocError::new(ErrorKind::Input, "the plugin returned no table"))?;
+        if table.abi_version != ABI_VERSION {
+            return Err(AocError::new(
+                ErrorKind::Input,
+                format!(
+                    "the plugin uses version {} of the interface, expected {}",
+                    table.abi_version, ABI_VERSION
+                ),
+            ));
+        }
+
+        let name = unsafe { CStr::from_ptr(table.name) }
+            .to_string_lossy()
+            .into_owned();
+        let days = unsafe { std::slice::from_raw_parts(table.days, table.len) };
+        Ok(days
+            .iter()
+            .map(|day| Variant {
+                name: name.clone(),
+                year: day.year,
+                day: day.day,
+                title: unsafe { CStr::from_ptr(day.title) }
+                    .to_string_lossy()
+                    .into_owned(),
+                parts: Part::ALL
+                    .into_iter()
+                    .filter(|&part| day.parts & part_bit(part) != 0)
+                    .collect(),
+                solve: day.solve,
+                free: table.free,
+            })
+            .collect())
+    }
+
+    /// Solves the `parts` of `content` with the plugin, like `Solution::run`.
+    pub fn run(
+        &self,
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+    ) -> Result<DayReport, AocError> {
+        DayReport::from_steps(|step| self.steps(content, parts, params, step))
+    }
+
+    /// Solves the `parts` of `content` with the plugin, like `Solution::steps`. The plugin parses
+    /// the input for every part, the time of the first parse is reported.
+    pub fn steps(
+        &self,
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+        step: &mut dyn FnMut(Step),
+    ) -> Result<(), AocError> {
+        if let Some(&part) = parts.iter().find(|part| !self.parts.contains(part)) {
+            return Err(AocError::new(
+                ErrorKind::NotImplemented,
+                format!("not implemented by plugin `{}`", self.name),
+            )
+            .with_day(self.day)
+            .with_part(part));
+        }
+
+        let params = CString::new(
+            params
+                .iter()
+                .map(|(key, value)| format!("{}={}\n", key, value))
+                .collect::<String>(),
+        )
+        .map_err(|_| AocError::new(ErrorKind::Param, "parameters must not contain NUL bytes"))?;
+
+        let mut parsed = false;
+        for &part in parts {
+            let timer = Instant::now();
+            let result = (self.solve)(
+                content.as_ptr(),
+                content.len(),
+                params.as_ptr(),
+                part_bit(part),
+            );
+            let elapsed = timer.elapsed();
+
+            let text = if result.text.is_null() {
+                String::new()
+            } else {
+                // SAFETY: the plugin returns NUL terminated strings, which it frees itself
+                let text = unsafe { CStr::from_ptr(result.text) }
+                    .to_string_lossy()
+                    .into_owned();
+                (self.free)(result.text);
+                text
+            };
+            let kind = kind_from_code(result.status);
+            if result.status != 0 && !parsed && kind == ErrorKind::Parse {
+                return Err(AocError::new(kind, text).with_day(self.day));
+            }
+            if !parsed {
+                step(Step::Parsed(Duration::from_nanos(result.parse_ns)));
+                parsed = true;
+            }
+            if result.status != 0 {
+                step(Step::Solved(Err(AocError::new(kind, text)
+                    .with_day(self.day)
+                    .with_part(part))));
+                continue;
+            }
+
+            // Text that doesn't survive the round trip, like leading zeros, is kept as it is
+            let answer = match Answer::parse(&text) {
+                answer if answer.to_string() == text => answer,
+                _ => Answer::Str(text),
+            };
+            step(Step::Solved(Ok(SolveReport {
+                part,
+                answer,
+                solve_time: Duration::from_nanos(result.solve_ns).min(elapsed),
+            })));
+        }
+        Ok(())
+    }
+}
+
+/// Exports the days of a plugin crate, built as `cdylib`, under the name of the variant.
+///
+/// ```ignore
+/// gen_plugin!("bitset"; (2024, 1, Day01, "Historian Hysteria"), (2024, 2, Day02, "Red-Nosed Reports"));
+/// ```
+#[macro_export]
+macro_rules! gen_plugin {
+    ( $name:expr; $(($year:expr, $day:expr, $d:ty, $title:expr)),* $(,)? ) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin() -> *const $crate::plugin::PluginTable {
+            static DAYS: &[$crate::plugin::PluginDay] = &[
+                $($crate::plugin::PluginDay::new::<$d>($year, $day, concat!($title, "\0"))),*
+            ];
+            static TABLE: $crate::plugin::PluginTable =
+                $crate::plugin::PluginTable::new(concat!($name, "\0"), DAYS);
+            &TABLE
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input<'a> = Vec<u64>;
+        const PARTS: &'static [Part] = &[Part::A];
+
+        fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+            Self::parse_with(content, &Params::new())
+        }
+
+        fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+            params.check(&["offset"])?;
+            let offset = params.get("offset", 0)?;
+            content
+                .split_whitespace()
+                .map(|n| crate::number::<u64>(content, n).map(|n| n + offset))
+                .collect()
+        }
+
+        fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            if input.is_empty() {
+                panic!("nothing to sum");
+            }
+            Ok(input.iter().sum::<u64>().into())
+        }
+
+        fn part_b<'a>(_: &Self::Input<'a>) -> Result<Answer, AocError> {
+            unreachable!()
+        }
+    }
+
+    gen_plugin!("test"; (2000, 1, Sum, "Sum It Up"));
+
+    #[test]
+    fn roundtrip() {
+        let variants = unsafe { Variant::from_table(aoc_plugin()) }.unwrap();
+        assert_eq!(variants.len(), 1);
+        let sum = &variants[0];
+        assert_eq!((sum.name.as_str(), sum.year, sum.day), ("test", 2000, 1));
+        assert_eq!(sum.title, "Sum It Up");
+        assert_eq!(sum.parts, [Part::A]);
+
+        let report = sum.run("1 2 3", &[Part::A], &Params::new()).unwrap();
+        assert_eq!(report.parts[0].answer, Answer::Int(6));
+        let params = Params::new().with("offset", 1);
+        let report = sum.run("1 2 3", &[Part::A], &params).unwrap();
+        assert_eq!(report.parts[0].answer, Answer::Int(9));
+    }
+
+    #[test]
+    fn errors_cross_the_boundary() {
+        let sum = unsafe { Variant::from_table(aoc_plugin()) }
+            .unwrap()
+            .remove(0);
+
+        let err = sum.run("1 x", &[Part::A], &Params::new()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Parse);
+        assert!(err.message.contains("`x`"));
+
+        let err = sum.run("", &[Part::A], &Params::new()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Panic);
+        assert!(err.message.contains("nothing to sum"));
+
+        let err = sum.run("1", &[Part::B], &Params::new()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotImplemented);
+        let err = sum
+            .run("1", &[Part::A], &Params::new().with("size", 1))
+            .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Param);
+    }
+}
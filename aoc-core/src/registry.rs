@@ -0,0 +1,180 @@
+use crate::bench::{BenchConfig, Measurement};
+use crate::{AocError, DayReport, Params, Part, Solution, Step};
+
+type RunFn = fn(&str, &[Part], &Params) -> Result<DayReport, AocError>;
+type StepsFn = fn(&str, &[Part], &Params, &mut dyn FnMut(Step)) -> Result<(), AocError>;
+type BenchFn = fn(&str, &[Part], &Params, BenchConfig) -> Result<Vec<Measurement>, AocError>;
+
+/// A solved day together with the type erased entry points of its `Solution`.
+///
+/// The years declare their days with `gen_solve!`, which collects them in a `DAYS` slice.
+#[derive(Clone, Copy, Debug)]
+pub struct DayEntry {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    /// The parts that are actually solved
+    pub parts: &'static [Part],
+    run: RunFn,
+    steps: StepsFn,
+    bench: BenchFn,
+}
+
+impl DayEntry {
+    pub const fn new<S: Solution>(year: u32, day: u32, title: &'static str) -> Self {
+        Self {
+            year,
+            day,
+            title,
+            parts: S::PARTS,
+            run: S::run,
+            steps: S::steps,
+            bench: S::bench,
+        }
+    }
+
+    /// Fails with a "not implemented" error if one of the `parts` isn't solved.
+    pub fn check_parts(&self, parts: &[Part]) -> Result<(), AocError> {
+        match parts.iter().find(|part| !self.parts.contains(part)) {
+            Some(&part) => Err(AocError::not_implemented(self.year, self.day).with_part(part)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn run(
+        &self,
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+    ) -> Result<DayReport, AocError> {
+        self.check_parts(parts)?;
+        (self.run)(content, parts, params).map_err(|e| e.with_day(self.day))
+    }
+
+    /// Solves the `parts` like [`Solution::steps`], handing every step to `step`.
+    pub fn steps(
+        &self,
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+        step: &mut dyn FnMut(Step),
+    ) -> Result<(), AocError> {
+        self.check_parts(parts)?;
+        (self.steps)(content, parts, params, step).map_err(|e| e.with_day(self.day))
+    }
+
+    pub fn bench(
+        &self,
+        content: &str,
+        parts: &[Part],
+        params: &Params,
+        config: BenchConfig,
+    ) -> Result<Vec<Measurement>, AocError> {
+        self.check_parts(parts)?;
+        (self.bench)(content, parts, params, config).map_err(|e| e.with_day(self.day))
+    }
+}
+
+/// All days known to the runner, ordered by year and day.
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    days: Vec<&'static DayEntry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the days of a year, usually the `DAYS` generated by `gen_solve!`.
+    pub fn register(&mut self, days: &'static [DayEntry]) -> &mut Self {
+        self.days.extend(days);
+        self.days.sort_by_key(|entry| (entry.year, entry.day));
+        self.days.dedup_by_key(|entry| (entry.year, entry.day));
+        self
+    }
+
+    /// All registered days, optionally restricted to one year.
+    pub fn days(&self, year: Option<u32>) -> impl Iterator<Item = &'static DayEntry> + '_ {
+        self.days
+            .iter()
+            .copied()
+            .filter(move |entry| year.is_none_or(|year| entry.year == year))
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        let mut years = self.days.iter().map(|entry| entry.year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<&'static DayEntry, AocError> {
+        self.days(Some(year))
+            .find(|entry| entry.day == day)
+            .ok_or_else(|| AocError::not_implemented(year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ErrorKind};
+
+    struct Count;
+    impl Solution for Count {
+        type Input<'a> = usize;
+        const PARTS: &'static [Part] = &[Part::A];
+
+        fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+            Ok(content.lines().count())
+        }
+
+        fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            Ok((*input).into())
+        }
+
+        fn part_b<'a>(_input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            unreachable!()
+        }
+    }
+
+    static DAYS: &[DayEntry] = &[
+        DayEntry::new::<Count>(2023, 3, "Count"),
+        DayEntry::new::<Count>(2023, 1, "Count"),
+    ];
+
+    #[test]
+    fn lookup_and_listing() {
+        let mut registry = Registry::new();
+        registry.register(DAYS);
+
+        let days = registry.days(None).map(|e| e.day).collect::<Vec<_>>();
+        assert_eq!(days, vec![1, 3]);
+        assert_eq!(registry.years(), vec![2023]);
+        assert_eq!(registry.days(Some(2024)).count(), 0);
+
+        let report = registry
+            .get(2023, 3)
+            .unwrap()
+            .run("x\ny", &[Part::A], &Params::new())
+            .unwrap();
+        assert_eq!(report.parts[0].answer, Answer::Int(2));
+    }
+
+    #[test]
+    fn missing_days_and_parts() {
+        let mut registry = Registry::new();
+        registry.register(DAYS);
+
+        let err = registry.get(2023, 2).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotImplemented);
+        assert_eq!(err.to_string(), "day 2: not implemented in 2023");
+
+        let err = registry
+            .get(2023, 1)
+            .unwrap()
+            .run("x", &Part::ALL, &Params::new())
+            .unwrap_err();
+        assert_eq!(err.to_string(), "day 1 part b: not implemented in 2023");
+    }
+}
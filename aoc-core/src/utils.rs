@@ -0,0 +1,906 @@
+use crate::AocError;
+
+mod geom;
+pub mod path;
+pub mod region;
+
+pub use geom::{Dir4, Dir8, Pos, Vec2};
+
+/// A rectangular grid, stored row by row in one contiguous `Vec`.
+#[derive(Clone)]
+pub struct Grid<T> {
+    entries: Vec<T>,
+    width: isize,
+    height: isize,
+    wrapping: bool,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for x in row {
+                write!(f, "{}", x)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new<F>(width: usize, height: usize, mut init: F) -> Self
+    where
+        F: FnMut(isize, isize) -> T,
+    {
+        let width = width as isize;
+        let height = height as isize;
+        let mut entries = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            entries.extend((0..width).map(|x| init(x, y)));
+        }
+        Self {
+            entries,
+            width,
+            height,
+            wrapping: false,
+        }
+    }
+
+    /// A grid of `width` columns from its entries in row-major order.
+    pub fn from_vec(width: usize, entries: Vec<T>) -> Self {
+        assert!(
+            entries.len().is_multiple_of(width),
+            "{} entries do not fill rows of length {}",
+            entries.len(),
+            width
+        );
+        let height = entries.len().checked_div(width).unwrap_or_default();
+        Self::from_parts(width, height, entries)
+    }
+
+    fn from_parts(width: usize, height: usize, entries: Vec<T>) -> Self {
+        debug_assert_eq!(width * height, entries.len());
+        Self {
+            entries,
+            width: width as isize,
+            height: height as isize,
+            wrapping: false,
+        }
+    }
+
+    pub fn set_wrapping(&mut self, wrapping: bool) {
+        self.wrapping = wrapping;
+    }
+
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    /// The position of `(x, y)` in the entries, which must be within the grid.
+    #[inline(always)]
+    fn offset(&self, x: isize, y: isize) -> usize {
+        (y * self.width + x) as usize
+    }
+
+    #[inline(always)]
+    fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            unsafe { Some(self.entries.get_unchecked(self.offset(x, y))) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            let offset = self.offset(x, y);
+            unsafe { Some(self.entries.get_unchecked_mut(offset)) }
+        } else {
+            None
+        }
+    }
+
+    pub fn get_wrapped(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width);
+        let y = y.rem_euclid(self.height);
+
+        unsafe { self.entries.get_unchecked(self.offset(x, y)) }
+    }
+
+    pub fn get_wrapped_mut(&mut self, x: isize, y: isize) -> &mut T {
+        let x = x.rem_euclid(self.width);
+        let y = y.rem_euclid(self.height);
+
+        let offset = self.offset(x, y);
+        unsafe { self.entries.get_unchecked_mut(offset) }
+    }
+
+    /// The entries of row `y`.
+    pub fn row(&self, y: isize) -> &[T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        let start = self.offset(0, y);
+        &self.entries[start..start + self.width as usize]
+    }
+
+    pub fn row_mut(&mut self, y: isize) -> &mut [T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        let start = self.offset(0, y);
+        &mut self.entries[start..start + self.width as usize]
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The entries of column `x` from top to bottom.
+    pub fn column(&self, x: isize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        assert!(x >= 0 && x < self.width, "column {} out of bounds", x);
+        self.entries
+            .iter()
+            .skip(x as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn column_mut(
+        &mut self,
+        x: isize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + '_ {
+        assert!(x >= 0 && x < self.width, "column {} out of bounds", x);
+        let width = self.width as usize;
+        self.entries.iter_mut().skip(x as usize).step_by(width)
+    }
+
+    /// All entries in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.entries
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.entries
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.entries
+    }
+
+    /// Parses one row per line, mapping each character with `f`. Characters `f` rejects and rows
+    /// of differing length are reported as parse errors.
+    pub fn try_parse<F>(content: &str, mut f: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut entries = Vec::with_capacity(content.len());
+        let mut width = None;
+        for line in content.lines() {
+            let start = entries.len();
+            for (i, c) in line.char_indices() {
+                entries.push(f(c).ok_or_else(|| {
+                    AocError::parse_at(content, &line[i..], format!("unexpected character `{}`", c))
+                })?);
+            }
+
+            let len = entries.len() - start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(AocError::parse_at(
+                        content,
+                        line,
+                        format!("expected a row of length {}, found {}", width, len),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let height = content.lines().count();
+        Ok(Self::from_parts(width.unwrap_or_default(), height, entries))
+    }
+
+    pub fn parse_with_default<F, G>(content: &str, mut f: F, mut default: G) -> Self
+    where
+        F: FnMut(char) -> T,
+        G: FnMut() -> T,
+    {
+        let width = content
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        let height = content.lines().count();
+
+        let mut entries = Vec::with_capacity(width * height);
+        for line in content.lines() {
+            let start = entries.len();
+            entries.extend(line.chars().map(&mut f));
+            for _ in entries.len() - start..width {
+                entries.push(default());
+            }
+        }
+
+        Self::from_parts(width, height, entries)
+    }
+}
+
+impl<T> std::ops::Index<(isize, isize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        if self.wrapping {
+            self.get_wrapped(x, y)
+        } else {
+            self.get(x, y).unwrap()
+        }
+    }
+}
+
+impl<T> std::ops::IndexMut<(isize, isize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (isize, isize)) -> &mut Self::Output {
+        if self.wrapping {
+            self.get_wrapped_mut(x, y)
+        } else {
+            self.get_mut(x, y).unwrap()
+        }
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self[(x as isize, y as isize)]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self[(x as isize, y as isize)]
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn get_pos(&self, pos: Pos) -> Option<&T> {
+        self.get(pos.x, pos.y)
+    }
+
+    pub fn get_pos_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.get_mut(pos.x, pos.y)
+    }
+}
+
+impl<T> std::ops::Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+impl<T> std::ops::IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self[(pos.x, pos.y)]
+    }
+}
+
+pub struct GridIter<'a, T> {
+    entries: std::iter::Enumerate<std::slice::Iter<'a, T>>,
+    width: usize,
+}
+
+impl<'a, T> Iterator for GridIter<'a, T> {
+    type Item = ((isize, isize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, entry) = self.entries.next()?;
+        Some((
+            ((i % self.width) as isize, (i / self.width) as isize),
+            entry,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, T> std::iter::IntoIterator for &'a Grid<T> {
+    type Item = ((isize, isize), &'a T);
+    type IntoIter = GridIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GridIter {
+            entries: self.entries.iter().enumerate(),
+            width: self.width as usize,
+        }
+    }
+}
+
+const GRID_NEIGHBOR_ORDER: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+pub struct GridNeighborsIter<'a, T> {
+    grid: &'a Grid<T>,
+    x: isize,
+    y: isize,
+    current: usize,
+    relevant: u8,
+}
+
+impl<'a, T> Iterator for GridNeighborsIter<'a, T> {
+    type Item = ((isize, isize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current < 8 && (self.relevant >> self.current) & 1 == 0 {
+            self.current += 1;
+        }
+
+        if self.current >= 8 {
+            return None;
+        }
+
+        let x = self.x + GRID_NEIGHBOR_ORDER[self.current].0;
+        let y = self.y + GRID_NEIGHBOR_ORDER[self.current].1;
+
+        let res = Some(((x, y), &self.grid[(x, y)]));
+        self.current += 1;
+        res
+    }
+}
+
+pub struct GridNeighborPositionsIter {
+    x: isize,
+    y: isize,
+    current: usize,
+    relevant: u8,
+}
+
+impl Iterator for GridNeighborPositionsIter {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current < 8 && (self.relevant >> self.current) & 1 == 0 {
+            self.current += 1;
+        }
+
+        if self.current >= 8 {
+            return None;
+        }
+
+        let x = self.x + GRID_NEIGHBOR_ORDER[self.current].0;
+        let y = self.y + GRID_NEIGHBOR_ORDER[self.current].1;
+
+        let res = Some((x, y));
+        self.current += 1;
+        res
+    }
+}
+
+impl<T> Grid<T> {
+    #[inline(always)]
+    fn relevant_neighbors(&self, x: isize, y: isize, mut relevant: u8) -> u8 {
+        if !self.wrapping {
+            for (i, offset) in GRID_NEIGHBOR_ORDER.iter().enumerate() {
+                let x = x + offset.0;
+                let y = y + offset.1;
+
+                if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+                    relevant &= 0xFF ^ 1 << i;
+                }
+            }
+        }
+        relevant
+    }
+
+    pub fn neighbors8(&self, x: isize, y: isize) -> GridNeighborsIter<'_, T> {
+        let relevant = self.relevant_neighbors(x, y, 0xFF);
+
+        GridNeighborsIter {
+            grid: self,
+            x,
+            y,
+            current: 0,
+            relevant,
+        }
+    }
+
+    pub fn neighbors4(&self, x: isize, y: isize) -> GridNeighborsIter<'_, T> {
+        let relevant = self.relevant_neighbors(x, y, 0b01011010);
+
+        GridNeighborsIter {
+            grid: self,
+            x,
+            y,
+            current: 0,
+            relevant,
+        }
+    }
+
+    pub fn neighborpos8(&self, x: isize, y: isize) -> GridNeighborPositionsIter {
+        let relevant = self.relevant_neighbors(x, y, 0xFF);
+
+        GridNeighborPositionsIter {
+            x,
+            y,
+            current: 0,
+            relevant,
+        }
+    }
+
+    pub fn neighborpos4(&self, x: isize, y: isize) -> GridNeighborPositionsIter {
+        let relevant = self.relevant_neighbors(x, y, 0b01011010);
+
+        GridNeighborPositionsIter {
+            x,
+            y,
+            current: 0,
+            relevant,
+        }
+    }
+}
+
+/// Geometric transforms, which return a new grid with the wrapping of `self`.
+impl<T: Clone> Grid<T> {
+    /// A grid of the given size whose entry at `(x, y)` is the one of `self` at `source(x, y)`.
+    fn transformed<F>(&self, width: isize, height: isize, source: F) -> Self
+    where
+        F: Fn(isize, isize) -> (isize, isize),
+    {
+        let mut grid = Self::new(width as usize, height as usize, |x, y| {
+            let (x, y) = source(x, y);
+            self.entries[self.offset(x, y)].clone()
+        });
+        grid.wrapping = self.wrapping;
+        grid
+    }
+
+    /// Mirrors the grid at its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.transformed(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates the grid clockwise, the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.transformed(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotates the grid counterclockwise, the right column becomes the top row.
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.transformed(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors the grid horizontally, the left column becomes the right one.
+    pub fn flip_h(&self) -> Self {
+        let width = self.width;
+        self.transformed(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors the grid vertically, the top row becomes the bottom one.
+    pub fn flip_v(&self) -> Self {
+        let height = self.height;
+        self.transformed(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+}
+
+/// Asserts that the rectangle at `(x, y)` lies within `width` x `height`.
+fn check_window(x: isize, y: isize, width: isize, height: isize, outer: (isize, isize)) {
+    assert!(
+        x >= 0 && y >= 0 && x + width <= outer.0 && y + height <= outer.1,
+        "window {}x{} at ({}, {}) exceeds {}x{}",
+        width,
+        height,
+        x,
+        y,
+        outer.0,
+        outer.1
+    );
+}
+
+impl<T> Grid<T> {
+    /// Borrows the `width` x `height` rectangle whose top left corner is `(x, y)`.
+    pub fn view(&self, x: isize, y: isize, width: usize, height: usize) -> GridView<'_, T> {
+        let (width, height) = (width as isize, height as isize);
+        check_window(x, y, width, height, (self.width, self.height));
+        GridView {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn view_mut(
+        &mut self,
+        x: isize,
+        y: isize,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'_, T> {
+        let (width, height) = (width as isize, height as isize);
+        check_window(x, y, width, height, (self.width, self.height));
+        GridViewMut {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// All `width` x `height` windows of the grid, row by row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let xs = (self.width + 1 - width as isize).max(0);
+        let ys = (self.height + 1 - height as isize).max(0);
+        (0..ys).flat_map(move |y| (0..xs).map(move |x| self.view(x, y, width, height)))
+    }
+}
+
+/// A borrowed rectangular window of a [`Grid`], indexed relative to its top left corner.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    x: isize,
+    y: isize,
+    width: isize,
+    height: isize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&'a T> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            None
+        } else {
+            self.grid.get(self.x + x, self.y + y)
+        }
+    }
+
+    pub fn row(&self, y: isize) -> &'a [T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        &self.grid.row(self.y + y)[self.x as usize..(self.x + self.width) as usize]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator + 'a {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    pub fn column(
+        &self,
+        x: isize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + 'a {
+        assert!(x >= 0 && x < self.width, "column {} out of bounds", x);
+        let view = *self;
+        (0..self.height).map(move |y| &view.grid[(view.x + x, view.y + y)])
+    }
+
+    /// A window of this window, relative to its top left corner.
+    pub fn view(&self, x: isize, y: isize, width: usize, height: usize) -> GridView<'a, T> {
+        let (width, height) = (width as isize, height as isize);
+        check_window(x, y, width, height, (self.width, self.height));
+        GridView {
+            grid: self.grid,
+            x: self.x + x,
+            y: self.y + y,
+            width,
+            height,
+        }
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(self.width as usize, self.height as usize, |x, y| {
+            self[(x, y)].clone()
+        })
+    }
+}
+
+impl<T> std::ops::Index<(isize, isize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        self.get(x, y).unwrap()
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self[(x as isize, y as isize)]
+    }
+}
+
+impl<T> std::ops::Index<Pos> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+pub struct GridViewIter<'a, T> {
+    view: GridView<'a, T>,
+    current: isize,
+}
+
+impl<'a, T> Iterator for GridViewIter<'a, T> {
+    type Item = ((isize, isize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.view.width * self.view.height {
+            return None;
+        }
+
+        let x = self.current % self.view.width;
+        let y = self.current / self.view.width;
+        self.current += 1;
+        Some(((x, y), &self.view.grid[(self.view.x + x, self.view.y + y)]))
+    }
+}
+
+impl<'a, T> std::iter::IntoIterator for GridView<'a, T> {
+    type Item = ((isize, isize), &'a T);
+    type IntoIter = GridViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        GridViewIter {
+            view: self,
+            current: 0,
+        }
+    }
+}
+
+impl<'a, T> std::iter::IntoIterator for &GridView<'a, T> {
+    type Item = ((isize, isize), &'a T);
+    type IntoIter = GridViewIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+/// A mutably borrowed rectangular window of a [`Grid`], see [`GridView`].
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    x: isize,
+    y: isize,
+    width: isize,
+    height: isize,
+}
+
+impl<T> GridViewMut<'_, T> {
+    pub fn width(&self) -> isize {
+        self.width
+    }
+
+    pub fn height(&self) -> isize {
+        self.height
+    }
+
+    /// Reborrows the window immutably.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.as_view().get(x, y)
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            None
+        } else {
+            self.grid.get_mut(self.x + x, self.y + y)
+        }
+    }
+
+    pub fn row(&self, y: isize) -> &[T] {
+        self.as_view().row(y)
+    }
+
+    pub fn row_mut(&mut self, y: isize) -> &mut [T] {
+        assert!(y >= 0 && y < self.height, "row {} out of bounds", y);
+        let (start, end) = (self.x as usize, (self.x + self.width) as usize);
+        &mut self.grid.row_mut(self.y + y)[start..end]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        self.as_view().rows()
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let (start, end) = (self.x as usize, (self.x + self.width) as usize);
+        let width = self.grid.width.max(1) as usize;
+        self.grid
+            .entries
+            .chunks_exact_mut(width)
+            .skip(self.y as usize)
+            .take(self.height as usize)
+            .map(move |row| &mut row[start..end])
+    }
+
+    pub fn iter(&self) -> GridViewIter<'_, T> {
+        self.as_view().into_iter()
+    }
+
+    /// The entries with their positions within the window, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((isize, isize), &mut T)> + '_ {
+        self.rows_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, entry)| ((x as isize, y as isize), entry))
+        })
+    }
+}
+
+impl<T> std::ops::Index<(isize, isize)> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        self.get(x, y).unwrap()
+    }
+}
+
+impl<T> std::ops::IndexMut<(isize, isize)> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, (x, y): (isize, isize)) -> &mut Self::Output {
+        self.get_mut(x, y).unwrap()
+    }
+}
+
+impl<T> std::ops::Index<Pos> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+impl<T> std::ops::IndexMut<Pos> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self[(pos.x, pos.y)]
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self[(x as isize, y as isize)]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self[(x as isize, y as isize)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, Pos};
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = Grid::from_vec(3, (0..6).collect());
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), &[3, 4, 5]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid[(1isize, 1isize)], 4);
+        assert_eq!(grid[Pos::new(2, 1)], 5);
+        assert_eq!(grid.get_pos(Pos::new(0, 2)), None);
+        assert_eq!(grid.get(3, 0), None);
+
+        grid.column_mut(0).for_each(|v| *v *= 10);
+        grid.row_mut(0)[1] = 7;
+        assert_eq!(grid.as_slice(), &[0, 7, 2, 30, 4, 5]);
+        assert_eq!(grid.to_string(), "072\n3045\n");
+
+        let positions = grid.into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+        assert_eq!(positions[4], (1, 1));
+    }
+
+    #[test]
+    fn transforms() {
+        // 0 1 2
+        // 3 4 5
+        let grid = Grid::from_vec(3, (0..6).collect());
+        assert_eq!(grid.transpose().as_slice(), &[0, 3, 1, 4, 2, 5]);
+        assert_eq!(grid.rotate_cw().as_slice(), &[3, 0, 4, 1, 5, 2]);
+        assert_eq!(grid.rotate_ccw().as_slice(), &[2, 5, 1, 4, 0, 3]);
+        assert_eq!(grid.flip_h().as_slice(), &[2, 1, 0, 5, 4, 3]);
+        assert_eq!(grid.flip_v().as_slice(), &[3, 4, 5, 0, 1, 2]);
+        assert_eq!(grid.rotate_cw().width(), 2);
+
+        let back = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(back.as_slice(), grid.as_slice());
+        let half = grid.rotate_cw().rotate_cw();
+        assert_eq!(half.as_slice(), grid.flip_h().flip_v().as_slice());
+    }
+
+    #[test]
+    fn views() {
+        let mut grid = Grid::new(4, 3, |x, y| x + 4 * y);
+        let view = grid.view(1, 1, 2, 2);
+        assert_eq!(view[(0isize, 0isize)], 5);
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.row(1), &[9, 10]);
+        assert_eq!(view.column(1).copied().collect::<Vec<_>>(), vec![6, 10]);
+        let entries = view.into_iter().collect::<Vec<_>>();
+        assert_eq!(entries[3], ((1, 1), &10));
+        assert_eq!(view.view(1, 0, 1, 2).to_grid().as_slice(), &[6, 10]);
+
+        assert_eq!(grid.windows(3, 2).count(), 4);
+        assert_eq!(grid.windows(5, 1).count(), 0);
+
+        let mut view = grid.view_mut(2, 0, 2, 3);
+        view[(1isize, 2isize)] = -1;
+        view.iter_mut().for_each(|((x, _), v)| *v *= x + 1);
+        assert_eq!(view.row(0), &[2, 6]);
+        assert_eq!(grid.column(3).copied().collect::<Vec<_>>(), vec![6, 14, -2]);
+    }
+
+    #[test]
+    fn neighbors_respect_bounds() {
+        let grid = Grid::new(3, 3, |x, y| x + 3 * y);
+
+        let corner = grid.neighbors4(0, 0).map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(corner, vec![1, 3]);
+
+        let center = grid.neighbors8(1, 1).map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(center, vec![0, 1, 2, 3, 5, 6, 7, 8]);
+
+        let positions = grid.neighborpos4(2, 2).collect::<Vec<_>>();
+        assert_eq!(positions, vec![(2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn neighbors_wrap_around() {
+        let mut grid = Grid::new(3, 3, |x, y| x + 3 * y);
+        grid.set_wrapping(true);
+
+        let corner = grid.neighbors4(0, 0).map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(corner, vec![6, 2, 1, 3]);
+    }
+}
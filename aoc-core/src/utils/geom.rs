@@ -0,0 +1,384 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::AocError;
+
+/// A position on a grid, `y` grows downwards like the rows of the puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The difference of two positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Pos) -> usize {
+        (other - self).chebyshev()
+    }
+
+    /// The four positions sharing an edge with this one, clockwise starting north.
+    pub fn neighbors4(self) -> [Pos; 4] {
+        Dir4::ALL.map(|dir| self + dir)
+    }
+
+    /// The eight positions sharing an edge or a corner with this one, clockwise starting north.
+    pub fn neighbors8(self) -> [Pos; 8] {
+        Dir8::ALL.map(|dir| self + dir)
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl From<(isize, isize)> for Pos {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Pos> for (isize, isize) {
+    fn from(pos: Pos) -> Self {
+        (pos.x, pos.y)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Vec2> for (isize, isize) {
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl Add<Vec2> for Pos {
+    type Output = Pos;
+
+    fn add(self, v: Vec2) -> Pos {
+        Pos::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vec2> for Pos {
+    fn add_assign(&mut self, v: Vec2) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vec2> for Pos {
+    type Output = Pos;
+
+    fn sub(self, v: Vec2) -> Pos {
+        Pos::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vec2> for Pos {
+    fn sub_assign(&mut self, v: Vec2) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Vec2;
+
+    fn sub(self, other: Pos) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the four directions along the axes, in clockwise order so `dir as usize` can index
+/// per-direction tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step in this direction, north is up.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir4::N => Vec2::new(0, -1),
+            Dir4::E => Vec2::new(1, 0),
+            Dir4::S => Vec2::new(0, 1),
+            Dir4::W => Vec2::new(-1, 0),
+        }
+    }
+
+    /// Whether this is `N` or `S`.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::N | Dir4::S)
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = AocError;
+
+    /// Accepts arrows `^>v<`, compass points `NESW` and `UDLR`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Dir4::N),
+            '>' | 'E' | 'R' => Ok(Dir4::E),
+            'v' | 'S' | 'D' => Ok(Dir4::S),
+            '<' | 'W' | 'L' => Ok(Dir4::W),
+            _ => Err(AocError::parse(format!(
+                "expected a direction, found `{}`",
+                c
+            ))),
+        }
+    }
+}
+
+impl std::str::FromStr for Dir4 {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(AocError::parse(format!(
+                "expected a direction, found `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// One of the eight directions including the diagonals, in clockwise order starting north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Turns by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir8::N => Vec2::new(0, -1),
+            Dir8::NE => Vec2::new(1, -1),
+            Dir8::E => Vec2::new(1, 0),
+            Dir8::SE => Vec2::new(1, 1),
+            Dir8::S => Vec2::new(0, 1),
+            Dir8::SW => Vec2::new(-1, 1),
+            Dir8::W => Vec2::new(-1, 0),
+            Dir8::NW => Vec2::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl std::str::FromStr for Dir8 {
+    type Err = AocError;
+
+    /// Accepts everything [`Dir4`] does and the compass points `NE`, `SE`, `SW` and `NW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NE),
+            "SE" => Ok(Dir8::SE),
+            "SW" => Ok(Dir8::SW),
+            "NW" => Ok(Dir8::NW),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+impl Add<Dir4> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir4) -> Pos {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign<Dir4> for Pos {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self += dir.offset();
+    }
+}
+
+impl Add<Dir8> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir8) -> Pos {
+        self + dir.offset()
+    }
+}
+
+impl AddAssign<Dir8> for Pos {
+    fn add_assign(&mut self, dir: Dir8) {
+        *self += dir.offset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Pos::new(1, 2);
+        let b = Pos::new(4, -2);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a) * 2, Pos::new(7, -6));
+        assert_eq!(a - -Vec2::new(1, 1), Pos::new(2, 3));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a + Dir4::N, Pos::new(1, 1));
+        assert_eq!(a + Dir8::SW, Pos::new(0, 3));
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Dir4::N.turn_left(), Dir4::W);
+        assert_eq!(Dir4::W.turn_right(), Dir4::N);
+        assert_eq!(Dir4::E.opposite(), Dir4::W);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.opposite(), Dir8::SW);
+        assert_eq!(Dir8::from(Dir4::S), Dir8::S);
+        assert!(Dir4::ALL
+            .iter()
+            .all(|&d| Dir8::from(d).offset() == d.offset()));
+
+        let parsed = "^>v<NESWUDLR"
+            .chars()
+            .map(|c| Dir4::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed[..4], Dir4::ALL);
+        assert_eq!(parsed[4..8], Dir4::ALL);
+        assert_eq!(parsed[8..], [Dir4::N, Dir4::S, Dir4::W, Dir4::E]);
+        assert_eq!("SW".parse::<Dir8>().unwrap(), Dir8::SW);
+        assert!("x".parse::<Dir4>().is_err());
+    }
+}
@@ -0,0 +1,423 @@
+//! Shortest paths over arbitrary state spaces, described by the states to start from and a
+//! function listing the successors of a state.
+//!
+//! ```ignore
+//! let paths = path::dijkstra([start], |&(pos, dir)| moves(pos, dir), |&(pos, _)| pos == end);
+//! let tiles = paths.on_shortest_paths(paths.goals());
+//! ```
+//!
+//! Every search records all predecessors on cheapest paths, so the cheapest paths form a DAG that
+//! can be walked backwards from any reached state. Searches stop once every goal reachable at the
+//! lowest cost has been found, or explore everything reachable if no state is a goal.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::FxHashMap;
+use smallvec::{smallvec, SmallVec};
+
+use super::{Dir4, Grid, Pos};
+
+/// The costs the searches can add up and compare.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// The outcome of a search.
+#[derive(Clone, Debug)]
+pub struct Paths<S, C> {
+    nodes: FxHashMap<S, Node<S, C>>,
+    goals: Vec<S>,
+}
+
+/// The cheapest cost of a reached state and the states it is reached from at that cost.
+#[derive(Clone, Debug)]
+struct Node<S, C> {
+    cost: C,
+    preds: SmallVec<[S; 1]>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Paths<S, C> {
+    fn new() -> Self {
+        Self {
+            nodes: FxHashMap::default(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records a start, returns whether it wasn't reached before.
+    fn start(&mut self, state: S) -> bool {
+        match self.nodes.entry(state) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(Node {
+                    cost: C::default(),
+                    preds: SmallVec::new(),
+                });
+                true
+            }
+        }
+    }
+
+    /// Records `cost` for `state` reached from `pred`, returns whether it is a new best.
+    fn relax(&mut self, pred: &S, state: S, cost: C) -> bool {
+        match self.nodes.entry(state) {
+            Entry::Occupied(mut entry) => {
+                let node = entry.get_mut();
+                match cost.cmp(&node.cost) {
+                    Ordering::Greater => false,
+                    Ordering::Equal => {
+                        node.preds.push(pred.clone());
+                        false
+                    }
+                    Ordering::Less => {
+                        node.cost = cost;
+                        node.preds.clear();
+                        node.preds.push(pred.clone());
+                        true
+                    }
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Node {
+                    cost,
+                    preds: smallvec![pred.clone()],
+                });
+                true
+            }
+        }
+    }
+
+    /// The cost of the cheapest path to `state`, `None` if it wasn't reached.
+    pub fn dist(&self, state: &S) -> Option<C> {
+        self.nodes.get(state).map(|node| node.cost)
+    }
+
+    /// All reached states with their cheapest costs, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> + '_ {
+        self.nodes.iter().map(|(state, node)| (state, node.cost))
+    }
+
+    /// The states preceding `state` on its cheapest paths, none for the starts.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.nodes.get(state).map_or(&[], |node| &node.preds)
+    }
+
+    /// The goals reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest path to a goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.dist(goal))
+    }
+
+    /// One cheapest path from a start to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.nodes.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(pred) = self.predecessors(path.last().unwrap()).first() {
+            path.push(pred.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All states on any cheapest path to one of `targets`, including the targets.
+    pub fn on_shortest_paths<'a>(&self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut todo = targets
+            .into_iter()
+            .filter(|target| self.nodes.contains_key(*target))
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(state) = todo.pop() {
+            if seen.insert(state.clone()) {
+                todo.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth first search, every step costs one.
+pub fn bfs<S, I, F, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut is_goal: G,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.start(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = paths.nodes[&state].cost;
+        if paths.cost().is_some_and(|best| cost > best) {
+            break;
+        }
+        if is_goal(&state) {
+            paths.goals.push(state.clone());
+        }
+        for next in successors(&state) {
+            if paths.relax(&state, next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, `successors` yields the next states with the cost of getting there.
+pub fn dijkstra<S, C, I, F, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    is_goal: G,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A state waiting in the queue of [`astar`], ordered by its estimated total cost only.
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates prefer the states closer to the goal
+        (&self.estimate, Reverse(&self.cost)).cmp(&(&other.estimate, Reverse(&other.cost)))
+    }
+}
+
+/// A* search, `heuristic` has to underestimate the remaining cost to a goal and must not drop
+/// by more than the cost of a step, otherwise the paths found aren't the cheapest.
+pub fn astar<S, C, I, F, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if paths.start(start.clone()) {
+            queue.push(Reverse(Queued {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            }));
+        }
+    }
+
+    while let Some(Reverse(Queued {
+        estimate,
+        cost,
+        state,
+    })) = queue.pop()
+    {
+        if paths.nodes[&state].cost < cost {
+            // Reached more cheaply after this entry was queued
+            continue;
+        }
+        if paths.cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+        if is_goal(&state) {
+            paths.goals.push(state.clone());
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if paths.relax(&state, next.clone(), cost) {
+                queue.push(Reverse(Queued {
+                    estimate: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                }));
+            }
+        }
+    }
+    paths
+}
+
+/// Searches between the cells of a grid, moving to the four neighbors of a cell.
+impl<T> Grid<T> {
+    /// Breadth first search through the cells `passable` accepts.
+    pub fn bfs<P, G>(
+        &self,
+        starts: impl IntoIterator<Item = Pos>,
+        mut passable: P,
+        is_goal: G,
+    ) -> Paths<Pos, usize>
+    where
+        P: FnMut(&T) -> bool,
+        G: FnMut(&Pos) -> bool,
+    {
+        bfs(
+            starts,
+            |&pos| {
+                pos.neighbors4()
+                    .into_iter()
+                    .filter(|&next| self.get_pos(next).is_some_and(&mut passable))
+                    .collect::<Vec<_>>()
+            },
+            is_goal,
+        )
+    }
+
+    /// Dijkstra's algorithm where entering a cell costs `cost` of the cell, `None` for walls.
+    pub fn dijkstra<C, F, G>(
+        &self,
+        starts: impl IntoIterator<Item = Pos>,
+        mut cost: F,
+        is_goal: G,
+    ) -> Paths<Pos, C>
+    where
+        C: Cost,
+        F: FnMut(&T) -> Option<C>,
+        G: FnMut(&Pos) -> bool,
+    {
+        dijkstra(
+            starts,
+            |&pos| {
+                Dir4::ALL
+                    .into_iter()
+                    .filter_map(|dir| {
+                        let next = pos + dir;
+                        Some((next, cost(self.get_pos(next)?)?))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            is_goal,
+        )
+    }
+}
+
+impl<C: Cost> Paths<Pos, C> {
+    /// The costs as a grid of the given size, `None` for the cells that weren't reached.
+    pub fn to_grid(&self, width: usize, height: usize) -> Grid<Option<C>> {
+        Grid::new(width, height, |x, y| self.dist(&Pos::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two equally long ways around a wall and a dead end in the top right corner.
+    const MAZE: &str = "\
+S...
+.#.#
+...E";
+
+    fn maze() -> Grid<char> {
+        Grid::try_parse(MAZE, Some).unwrap()
+    }
+
+    #[test]
+    fn bfs_on_grid() {
+        let grid = maze();
+        let end = Pos::new(3, 2);
+        let paths = grid.bfs([Pos::ORIGIN], |&c| c != '#', |&pos| pos == end);
+
+        assert_eq!(paths.cost(), Some(5));
+        assert_eq!(paths.goals(), &[end]);
+        let path = paths.path_to(&end).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (Pos::ORIGIN, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        // Both ways around the wall, but not the dead end
+        let tiles = paths.on_shortest_paths(paths.goals());
+        assert_eq!(tiles.len(), 9);
+        assert!(!tiles.contains(&Pos::new(3, 0)));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let grid = Grid::new(6, 6, |x, y| ((x * 7 + y * 3) % 5 + 1) as u32);
+        let end = Pos::new(5, 5);
+        let paths = grid.dijkstra([Pos::ORIGIN], |&c| Some(c), |&pos| pos == end);
+        let estimate = astar(
+            [Pos::ORIGIN],
+            |&pos| {
+                pos.neighbors4()
+                    .into_iter()
+                    .filter_map(|next| Some((next, *grid.get_pos(next)?)))
+                    .collect::<Vec<_>>()
+            },
+            |pos| pos.manhattan(end) as u32,
+            |&pos| pos == end,
+        );
+
+        assert_eq!(paths.cost(), estimate.cost());
+        assert!(estimate.distances().count() <= paths.distances().count());
+        let full = grid.dijkstra([Pos::ORIGIN], |&c| Some(c), |_| false);
+        assert_eq!(full.to_grid(6, 6)[end], paths.cost());
+        assert_eq!(full.distances().count(), 36);
+    }
+
+    #[test]
+    fn all_cheapest_goals() {
+        // Two goals at the same cost, one more expensive
+        let paths = dijkstra(
+            [0u32],
+            |&n| match n {
+                0 => vec![(1, 2), (2, 1), (3, 5)],
+                2 => vec![(1, 1)],
+                _ => vec![],
+            },
+            |&n| n == 1 || n == 3,
+        );
+        assert_eq!(paths.goals(), &[1]);
+        assert_eq!(paths.predecessors(&1), &[0, 2]);
+        assert_eq!(paths.on_shortest_paths([&1]).len(), 3);
+        assert_eq!(paths.dist(&3), Some(5));
+    }
+}
@@ -0,0 +1,297 @@
+//! Connected regions of grid cells and their measures.
+//!
+//! ```ignore
+//! let plots = garden.components_by(Connectivity::Four, |a, b| a == b);
+//! let price = plots.regions().iter().map(|r| r.area() * r.perimeter()).sum::<usize>();
+//! ```
+
+use std::collections::VecDeque;
+
+use super::{Dir4, Dir8, Grid, Pos};
+
+/// Which cells count as neighbors when growing a region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    fn neighbors(self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let dirs: &[Dir8] = match self {
+            Connectivity::Four => &[Dir8::N, Dir8::E, Dir8::S, Dir8::W],
+            Connectivity::Eight => &Dir8::ALL,
+        };
+        dirs.iter().map(move |&dir| pos + dir)
+    }
+}
+
+/// A non-empty set of cells of a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    /// Sorted, so membership is a binary search
+    cells: Vec<Pos>,
+}
+
+impl Region {
+    fn new(mut cells: Vec<Pos>) -> Self {
+        cells.sort_unstable();
+        Self { cells }
+    }
+
+    /// The cells of the region, ordered by column and then by row.
+    pub fn cells(&self) -> &[Pos] {
+        &self.cells
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.binary_search(&pos).is_ok()
+    }
+
+    /// The number of cells.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges between the region and the cells outside of it.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|pos| pos.neighbors4())
+            .filter(|&next| !self.contains(next))
+            .count()
+    }
+
+    /// The number of corners of the outline including holes, which is also the number of its
+    /// straight sides.
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|&pos| {
+                Dir4::ALL.map(|dir| {
+                    let turned = dir.turn_right();
+                    let a = self.contains(pos + dir);
+                    let b = self.contains(pos + turned);
+                    let diagonal = self.contains(pos + dir + turned);
+                    (!a && !b) || (a && b && !diagonal)
+                })
+            })
+            .filter(|&corner| corner)
+            .count()
+    }
+
+    /// The top left and the bottom right corner of the smallest rectangle around the region.
+    pub fn bbox(&self) -> (Pos, Pos) {
+        let xs = (self.cells[0].x, self.cells[self.cells.len() - 1].x);
+        let (min_y, max_y) = self
+            .cells
+            .iter()
+            .fold((isize::MAX, isize::MIN), |(min, max), pos| {
+                (min.min(pos.y), max.max(pos.y))
+            });
+        (Pos::new(xs.0, min_y), Pos::new(xs.1, max_y))
+    }
+
+    /// Whether the region lies inside the closed loop through the centers of `path`, the last
+    /// position connecting back to the first. The region must not share cells with the loop.
+    pub fn enclosed_by(&self, path: &[Pos]) -> bool {
+        let pos = self.cells[0];
+        // Count the crossings of a ray going left, slightly below the center of the cell
+        let crossings = path
+            .iter()
+            .zip(path.iter().cycle().skip(1))
+            .filter(|&(a, b)| {
+                let (lo, hi) = if a.y <= b.y { (a, b) } else { (b, a) };
+                lo.y <= pos.y
+                    && pos.y < hi.y
+                    && (hi.x - lo.x) * (pos.y - lo.y) < (pos.x - lo.x) * (hi.y - lo.y)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+}
+
+/// All regions of a grid, with the index of the region every cell belongs to.
+#[derive(Clone)]
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Components {
+    /// The index of the region containing `pos`, `None` for cells outside of all regions.
+    pub fn label(&self, pos: Pos) -> Option<usize> {
+        self.labels.get_pos(pos).copied().flatten()
+    }
+
+    /// The region indices of all cells.
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    /// The regions, in the order of their first cell row by row.
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// The region containing `pos`.
+    pub fn region(&self, pos: Pos) -> Option<&Region> {
+        self.label(pos).map(|label| &self.regions[label])
+    }
+}
+
+impl<T> Grid<T> {
+    /// Labels `seed` and every cell reachable from it through neighbors `connected` accepts with
+    /// `label`, returns the labelled cells.
+    fn fill<F>(
+        &self,
+        seed: Pos,
+        connectivity: Connectivity,
+        labels: &mut Grid<Option<usize>>,
+        label: usize,
+        mut connected: F,
+    ) -> Vec<Pos>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        labels[seed] = Some(label);
+        let mut cells = vec![seed];
+        let mut queue = VecDeque::from([seed]);
+        while let Some(pos) = queue.pop_front() {
+            for next in connectivity.neighbors(pos) {
+                if labels.get_pos(next) == Some(&None) && connected(&self[pos], &self[next]) {
+                    labels[next] = Some(label);
+                    cells.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        cells
+    }
+
+    /// The region of the cells reachable from `seed` through cells `inside` accepts, `None` if
+    /// it doesn't accept `seed` itself.
+    pub fn flood_fill<F>(
+        &self,
+        seed: Pos,
+        connectivity: Connectivity,
+        mut inside: F,
+    ) -> Option<Region>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        if !inside(self.get_pos(seed)?) {
+            return None;
+        }
+        let mut labels = Grid::new(self.width() as usize, self.height() as usize, |_, _| None);
+        let cells = self.fill(seed, connectivity, &mut labels, 0, |_, next| inside(next));
+        Some(Region::new(cells))
+    }
+
+    /// The connected regions of the cells `inside` accepts.
+    pub fn components<F>(&self, connectivity: Connectivity, mut inside: F) -> Components
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut labels = Grid::new(self.width() as usize, self.height() as usize, |_, _| None);
+        let mut regions = Vec::new();
+        for ((x, y), cell) in self {
+            let seed = Pos::new(x, y);
+            if labels[seed].is_none() && inside(cell) {
+                let cells = self.fill(seed, connectivity, &mut labels, regions.len(), |_, next| {
+                    inside(next)
+                });
+                regions.push(Region::new(cells));
+            }
+        }
+        Components { labels, regions }
+    }
+
+    /// Splits the whole grid into regions, neighbors belong to the same region if `same` accepts
+    /// them.
+    pub fn components_by<F>(&self, connectivity: Connectivity, mut same: F) -> Components
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut labels = Grid::new(self.width() as usize, self.height() as usize, |_, _| None);
+        let mut regions = Vec::new();
+        for ((x, y), _) in self {
+            let seed = Pos::new(x, y);
+            if labels[seed].is_none() {
+                let cells = self.fill(seed, connectivity, &mut labels, regions.len(), &mut same);
+                regions.push(Region::new(cells));
+            }
+        }
+        Components { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GARDEN: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    fn garden_regions() {
+        let garden = Grid::try_parse(GARDEN, Some).unwrap();
+        let plots = garden.components_by(Connectivity::Four, |a, b| a == b);
+        let measures = plots
+            .regions()
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.corners()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            measures,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+
+        let c = plots.region(Pos::new(2, 1)).unwrap();
+        assert_eq!(c.bbox(), (Pos::new(2, 1), Pos::new(3, 3)));
+        assert_eq!(plots.label(Pos::new(3, 3)), plots.label(Pos::new(2, 1)));
+        assert_eq!(plots.label(Pos::new(4, 0)), None);
+
+        let filled = garden.flood_fill(Pos::new(3, 3), Connectivity::Four, |&c| c == 'C');
+        assert_eq!(filled.as_ref(), Some(c));
+        assert!(garden
+            .flood_fill(Pos::ORIGIN, Connectivity::Four, |&c| c == 'C')
+            .is_none());
+    }
+
+    #[test]
+    fn enclosed_regions() {
+        // A ring around one cell, with a wall and open cells touching it only at corners
+        let grid = Grid::try_parse("#...\n.###\n.#.#\n.###", |c| Some(c == '#')).unwrap();
+        let ring = [
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (3, 2),
+            (3, 3),
+            (2, 3),
+            (1, 3),
+            (1, 2),
+        ]
+        .map(|(x, y)| Pos::new(x, y));
+
+        let four = grid.components(Connectivity::Four, |&wall| !wall);
+        assert_eq!(four.regions().len(), 3);
+        let eight = grid.components(Connectivity::Eight, |&wall| wall);
+        assert_eq!(eight.regions().len(), 1);
+        assert_eq!(eight.regions()[0].area(), 9);
+
+        let enclosed = four
+            .regions()
+            .iter()
+            .filter(|r| r.enclosed_by(&ring))
+            .map(Region::area)
+            .collect::<Vec<_>>();
+        assert_eq!(enclosed, vec![1]);
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use aoc_core::Params;
+use clap::Args;
+use rayon::prelude::*;
+
+use crate::input::InputLocator;
+use crate::worker::Solved;
+use crate::REGISTRY;
+
+#[derive(Args, Debug)]
+pub struct AllArgs {
+    /// Only solve the days of this year
+    pub year: Option<u32>,
+
+    /// Solve the days concurrently on a thread pool
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// Additionally solve the days one after another and compare the timings
+    #[arg(long, requires = "parallel")]
+    pub sequential: bool,
+}
+
+/// The outcome of solving one day as part of `all`.
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    pub solved: Solved,
+    /// Time from reading the input until the last part is solved
+    pub wall_time: Duration,
+}
+
+/// All registered `(year, day)` pairs, optionally restricted to one year.
+pub fn days(year: Option<u32>) -> Vec<(u32, u32)> {
+    REGISTRY
+        .days(year)
+        .map(|entry| (entry.year, entry.day))
+        .collect()
+}
+
+fn run_day(year: u32, day: u32, locator: &InputLocator, timeout: Option<Duration>) -> DayRun {
+    let timer = Instant::now();
+    let solved = crate::solve(year, day, None, &Params::new(), locator, None, timeout);
+    DayRun {
+        year,
+        day,
+        solved,
+        wall_time: timer.elapsed(),
+    }
+}
+
+/// Solves all `days`, returns the runs in the order of `days` and the total elapsed time.
+pub fn run(
+    days: &[(u32, u32)],
+    locator: &InputLocator,
+    parallel: bool,
+    timeout: Option<Duration>,
+) -> (Vec<DayRun>, Duration) {
+    let timer = Instant::now();
+    let runs = if parallel {
+        days.par_iter()
+            .map(|&(year, day)| run_day(year, day, locator, timeout))
+            .collect()
+    } else {
+        days.iter()
+            .map(|&(year, day)| run_day(year, day, locator, timeout))
+            .collect()
+    };
+    (runs, timer.elapsed())
+}
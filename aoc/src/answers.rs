@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use aoc_core::{Answer, AocError, ErrorKind, Part};
+use serde::{Deserialize, Serialize};
+
+/// Default location of the recorded answers, relative to the working directory
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    a: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    b: Option<String>,
+}
+
+/// The known answers for the real puzzle inputs, stored as
+///
+/// ```toml
+/// [2023.5]
+/// a = "35"
+/// b = "46"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+impl Answers {
+    /// Loads the answers file, a missing file contains no answers.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(AocError::input(&path.display().to_string(), e)),
+        };
+
+        toml::from_str(&content).map_err(|e| {
+            AocError::new(
+                ErrorKind::Input,
+                format!("invalid answers file `{}`: {}", path.display(), e),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| {
+            AocError::new(ErrorKind::Input, format!("unable to store answers: {}", e))
+        })?;
+        std::fs::write(path, content).map_err(|e| AocError::input(&path.display().to_string(), e))
+    }
+
+    /// The recorded answer as it is displayed. Answers are compared by that text, the type of
+    /// an answer like `"0123"` can't be told from the file.
+    pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&str> {
+        let answers = self.years.get(&year.to_string())?.get(&day.to_string())?;
+        match part {
+            Part::A => answers.a.as_deref(),
+            Part::B => answers.b.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, part: Part, answer: &Answer) {
+        let answer = answer.to_string();
+        let answers = self
+            .years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default();
+        match part {
+            Part::A => answers.a = Some(answer),
+            Part::B => answers.b = Some(answer),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unrecorded,
+    NoInput,
+    MissingInput,
+    Failed,
+    Timeout,
+    Panicked,
+}
+
+/// The outcome of checking one part against the recorded answers.
+#[derive(Clone, Debug)]
+pub struct Verification {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    /// The computed answer or the error message
+    pub actual: String,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn check(
+        answers: &Answers,
+        year: u32,
+        day: u32,
+        part: Part,
+        actual: Result<&Answer, &AocError>,
+    ) -> Self {
+        // A missing input only fails a part with a recorded answer, not a day never solved
+        let (actual, verdict) = match (actual, answers.get(year, day, part)) {
+            (Err(e), Some(_)) if e.kind == ErrorKind::Input => {
+                (e.message.clone(), Verdict::MissingInput)
+            }
+            (Err(e), None) if e.kind == ErrorKind::Input => (e.message.clone(), Verdict::NoInput),
+            (Err(e), _) if e.kind == ErrorKind::Timeout => (e.message.clone(), Verdict::Timeout),
+            (Err(e), _) if e.kind == ErrorKind::Panic => (e.message.clone(), Verdict::Panicked),
+            (Err(e), _) => (e.message.clone(), Verdict::Failed),
+            (Ok(actual), None) => (actual.to_string(), Verdict::Unrecorded),
+            (Ok(actual), Some(expected)) if actual.to_string() == expected => {
+                (actual.to_string(), Verdict::Correct)
+            }
+            (Ok(actual), Some(expected)) => (
+                actual.to_string(),
+                Verdict::Wrong {
+                    expected: expected.to_owned(),
+                },
+            ),
+        };
+
+        Self {
+            year,
+            day,
+            part,
+            actual,
+            verdict,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.verdict,
+            Verdict::Wrong { .. }
+                | Verdict::MissingInput
+                | Verdict::Failed
+                | Verdict::Timeout
+                | Verdict::Panicked
+        )
+    }
+
+    /// Whether any part was compared against a recorded answer, verifying nothing must not pass.
+    pub fn any_checked(verifications: &[Verification]) -> bool {
+        verifications
+            .iter()
+            .any(|v| matches!(v.verdict, Verdict::Correct | Verdict::Wrong { .. }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(2023, 5, Part::A, &Answer::Int(35));
+        answers.set(2023, 5, Part::B, &Answer::Int(46));
+        answers.set(2024, 11, Part::A, &Answer::Int(55312));
+
+        let content = toml::to_string(&answers).unwrap();
+        assert!(content.contains("[2023.5]"));
+
+        let loaded: Answers = toml::from_str(&content).unwrap();
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(2023, 5, Part::B), Some("46"));
+        assert_eq!(loaded.get(2024, 11, Part::B), None);
+    }
+
+    #[test]
+    fn verdicts() {
+        let mut answers = Answers::default();
+        answers.set(2023, 1, Part::A, &Answer::Int(142));
+
+        let check = |part, actual| Verification::check(&answers, 2023, 1, part, Ok(&actual));
+        assert_eq!(check(Part::A, Answer::Int(142)).verdict, Verdict::Correct);
+        assert!(check(Part::A, Answer::Int(143)).is_failure());
+        assert_eq!(
+            check(Part::A, Answer::from("142")).verdict,
+            Verdict::Correct
+        );
+        assert_eq!(
+            check(Part::B, Answer::Int(281)).verdict,
+            Verdict::Unrecorded
+        );
+        assert!(!check(Part::B, Answer::Int(281)).is_failure());
+    }
+
+    #[test]
+    fn missing_inputs() {
+        let mut answers = Answers::default();
+        answers.set(2023, 1, Part::A, &Answer::Int(142));
+
+        let missing = AocError::new(ErrorKind::Input, "unable to read input");
+        let check = |part| Verification::check(&answers, 2023, 1, part, Err(&missing));
+        assert_eq!(check(Part::A).verdict, Verdict::MissingInput);
+        assert!(check(Part::A).is_failure());
+        assert_eq!(check(Part::B).verdict, Verdict::NoInput);
+        assert!(!check(Part::B).is_failure());
+        let checked = [check(Part::A), check(Part::B)];
+        assert!(!Verification::any_checked(&checked));
+    }
+
+    #[test]
+    fn recorded_answers_keep_their_text() {
+        let mut answers = Answers::default();
+        answers.set(2024, 17, Part::A, &Answer::from("4"));
+        answers.set(2024, 17, Part::B, &Answer::from("0123"));
+        let loaded: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+
+        let check = |part, actual| Verification::check(&loaded, 2024, 17, part, Ok(&actual));
+        assert_eq!(check(Part::A, Answer::from("4")).verdict, Verdict::Correct);
+        assert_eq!(
+            check(Part::B, Answer::from("0123")).verdict,
+            Verdict::Correct
+        );
+        assert!(check(Part::B, Answer::Int(123)).is_failure());
+    }
+}
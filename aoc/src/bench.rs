@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::bench::{BenchConfig, Measurement, Stage};
+use aoc_core::{AocError, ErrorKind, Params};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::input::InputLocator;
+use crate::{render, worker, REGISTRY};
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Only benchmark this year
+    year: Option<u32>,
+
+    /// Only benchmark this day of the year
+    #[arg(requires = "year")]
+    day: Option<u32>,
+
+    /// Number of measured runs of every stage
+    #[arg(short = 'n', long, default_value_t = BenchConfig::default().samples)]
+    samples: usize,
+
+    /// Number of unmeasured runs before the measurement starts
+    #[arg(long, default_value_t = BenchConfig::default().warmup)]
+    warmup: usize,
+
+    /// Store the median timings in this file, keeping the ones of the days not benchmarked
+    #[arg(long, value_name = "PATH")]
+    save_baseline: Option<PathBuf>,
+
+    /// Compare the median timings with the ones stored in this file
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown in percent relative to the baseline that counts as a regression
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// The median timings of a previous benchmark in nanoseconds, stored as
+///
+/// ```toml
+/// [2023.5]
+/// parse = 21000
+/// a = 5300
+/// b = 1200000
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| AocError::input(&path.display().to_string(), e))?;
+        toml::from_str(&content).map_err(|e| {
+            AocError::new(
+                ErrorKind::Input,
+                format!("invalid baseline `{}`: {}", path.display(), e),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| {
+            AocError::new(ErrorKind::Input, format!("unable to store baseline: {}", e))
+        })?;
+        std::fs::write(path, content).map_err(|e| AocError::input(&path.display().to_string(), e))
+    }
+
+    pub fn get(&self, year: u32, day: u32, stage: Stage) -> Option<Duration> {
+        let nanos = self
+            .years
+            .get(&year.to_string())?
+            .get(&day.to_string())?
+            .get(stage.to_string())?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, stage: Stage, median: Duration) {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default()
+            .insert(stage.to_string().to_owned(), median.as_nanos() as u64);
+    }
+}
+
+/// The relative change from `baseline` to `current` in percent.
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+/// The benchmark of a single day.
+pub struct DayBench {
+    pub day: u32,
+    pub measurements: Vec<Measurement>,
+}
+
+impl DayBench {
+    pub fn median(&self, stage: Stage) -> Option<Duration> {
+        self.measurements
+            .iter()
+            .find(|m| m.stage == stage)
+            .map(|m| m.stats().median)
+    }
+
+    /// The sum of the medians of all stages.
+    pub fn total(&self) -> Duration {
+        self.measurements.iter().map(|m| m.stats().median).sum()
+    }
+}
+
+/// Runs the benchmarks selected by `args`, returns `false` if a day failed or regressed.
+///
+/// Every day is benchmarked on a worker of its own, so a day that panics or doesn't finish within
+/// `timeout` fails alone.
+pub fn bench(args: &BenchArgs, locator: &InputLocator, timeout: Option<Duration>) -> bool {
+    let config = BenchConfig {
+        warmup: args.warmup,
+        samples: args.samples.max(1),
+    };
+    let baseline = match args.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    // The days benchmarked now replace their old timings, the others are kept
+    let saved = match &args.save_baseline {
+        Some(path) if path.exists() => Baseline::load(path),
+        _ => Ok(Baseline::default()),
+    };
+    let mut saved = match saved {
+        Ok(saved) => saved,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+
+    let days = match (args.year, args.day) {
+        (Some(year), Some(day)) => match REGISTRY.get(year, day) {
+            Ok(entry) => vec![entry],
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return false;
+            }
+        },
+        (year, _) => REGISTRY.days(year).collect(),
+    };
+    if days.is_empty() {
+        eprintln!("error: no solved days to benchmark");
+        return false;
+    }
+
+    let mut ok = true;
+    for year in REGISTRY.years() {
+        let mut results = Vec::new();
+        for &entry in days.iter().filter(|entry| entry.year == year) {
+            let day = entry.day;
+            let measurements =
+                crate::load(entry.year, entry.day, locator, None).and_then(|content| {
+                    worker::isolate(
+                        format!("bench {} day {:0>2}", year, day),
+                        timeout,
+                        move || entry.bench(&content, entry.parts, &Params::new(), config),
+                    )
+                    .map_err(|e| e.with_day(day))?
+                });
+            let measurements = match measurements {
+                Ok(measurements) => measurements,
+                Err(e) if e.kind == ErrorKind::Input && args.day.is_none() => {
+                    println!("{} day {:0>2}: skipped, no input", year, day);
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    ok = false;
+                    continue;
+                }
+            };
+
+            let result = DayBench { day, measurements };
+            ok &= render::bench_day(year, &result, baseline.as_ref(), args.threshold);
+            for m in &result.measurements {
+                saved.set(year, day, m.stage, m.stats().median);
+            }
+            results.push(result);
+        }
+
+        if !results.is_empty() {
+            render::bench_summary(year, &results);
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        if let Err(e) = saved.save(path) {
+            eprintln!("error: {}", e);
+            ok = false;
+        }
+    }
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    #[test]
+    fn baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.set(2023, 5, Stage::Parse, Duration::from_micros(21));
+        baseline.set(2023, 5, Stage::Solve(Part::B), Duration::from_millis(12));
+
+        let content = toml::to_string(&baseline).unwrap();
+        assert!(content.contains("[2023.5]"));
+        assert!(content.contains("parse = 21000"));
+
+        let loaded: Baseline = toml::from_str(&content).unwrap();
+        assert_eq!(loaded, baseline);
+        assert_eq!(
+            loaded.get(2023, 5, Stage::Solve(Part::B)),
+            Some(Duration::from_millis(12))
+        );
+        assert_eq!(loaded.get(2023, 5, Stage::Solve(Part::A)), None);
+    }
+
+    #[test]
+    fn relative_change() {
+        let ms = Duration::from_millis;
+        assert!((change(ms(100), ms(150)) - 50.0).abs() < 1e-9);
+        assert!((change(ms(100), ms(80)) + 20.0).abs() < 1e-9);
+        assert_eq!(change(Duration::ZERO, ms(1)), 0.0);
+    }
+}
@@ -0,0 +1,238 @@
+use std::path::PathBuf;
+
+use aoc_core::{AocError, ErrorKind};
+use clap::Args;
+
+use crate::http::Http;
+use crate::input::InputLocator;
+use crate::REGISTRY;
+
+/// Name of the environment variable holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Name of the environment variable overriding the URL of the puzzle server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Where the puzzle server is and how to log in.
+#[derive(Args, Clone, Debug)]
+pub struct Server {
+    /// URL of the puzzle server, e.g. a local stand-in for testing
+    #[arg(long, value_name = "URL", env = BASE_URL_VAR, default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+
+    /// The session cookie, only read from the environment so it never shows up in the shell history
+    #[arg(skip = std::env::var(SESSION_VAR).ok())]
+    pub session: Option<String>,
+}
+
+impl Server {
+    /// The URL of the puzzle of a day, e.g. `<base>/2023/day/5`.
+    pub fn day_url(&self, year: u32, day: u32) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        )
+    }
+
+    pub fn session(&self) -> Result<&str, AocError> {
+        self.session
+            .as_deref()
+            .filter(|session| !session.trim().is_empty())
+            .map(str::trim)
+            .ok_or_else(|| {
+                AocError::new(
+                    ErrorKind::Remote,
+                    format!("no session cookie, set `{}`", SESSION_VAR),
+                )
+            })
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct FetchArgs {
+    /// The year of the event
+    year: u32,
+
+    /// Only fetch the input of this day, all solved days of the year otherwise
+    day: Option<u32>,
+
+    #[command(flatten)]
+    server: Server,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already stored at this path
+    Cached(PathBuf),
+    /// The input was downloaded to this path
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of a day unless it is already stored at its cache path. Inputs elsewhere,
+/// e.g. in the per-year crate, don't count, the cache is what `fetch` maintains.
+pub fn fetch(
+    http: &dyn Http,
+    server: &Server,
+    locator: &InputLocator,
+    year: u32,
+    day: u32,
+) -> Result<Fetched, AocError> {
+    let path = locator.cache_path(year, day);
+    if path.is_file() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = format!("{}/input", server.day_url(year, day));
+    let response = http.get(&url, server.session()?)?;
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or_default().trim();
+        return Err(AocError::new(
+            ErrorKind::Remote,
+            format!(
+                "unable to fetch the input of year {} (status {}): {}",
+                year, response.status, reason
+            ),
+        )
+        .with_day(day));
+    }
+
+    let display = path.display().to_string();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| AocError::input(&display, e))?;
+    }
+    std::fs::write(&path, response.body).map_err(|e| AocError::input(&display, e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Fetches the inputs selected by `args`, returns `false` if any of them failed.
+pub fn run(args: &FetchArgs, http: &dyn Http, locator: &InputLocator) -> bool {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => REGISTRY
+            .days(Some(args.year))
+            .map(|entry| entry.day)
+            .collect(),
+    };
+
+    let mut ok = true;
+    for day in days {
+        match fetch(http, &args.server, locator, args.year, day) {
+            Ok(Fetched::Cached(path)) => {
+                println!(
+                    "{} day {:0>2}: cached at {}",
+                    args.year,
+                    day,
+                    path.display()
+                )
+            }
+            Ok(Fetched::Downloaded(path)) => {
+                println!("{} day {:0>2}: saved to {}", args.year, day, path.display())
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::http::Response;
+
+    /// Answers every request with `response` and records the requested URLs.
+    struct Mock {
+        response: Response,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Mock {
+        fn new(status: u16, body: &str) -> Self {
+            Self {
+                response: Response {
+                    status,
+                    body: body.to_owned(),
+                },
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    fn server(session: Option<&str>) -> Server {
+        Server {
+            base_url: "http://localhost:1234/".to_owned(),
+            session: session.map(str::to_owned),
+        }
+    }
+
+    impl Http for Mock {
+        fn get(&self, url: &str, session: &str) -> Result<Response, AocError> {
+            assert_eq!(session, "secret");
+            self.requests.borrow_mut().push(url.to_owned());
+            Ok(self.response.clone())
+        }
+
+        fn post_form(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<Response, AocError> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = InputLocator::new(Some(dir.path().to_owned()));
+        let server = server(Some("secret"));
+        let http = Mock::new(200, "1 2 3\n");
+
+        let path = dir.path().join("2023").join("day05.txt");
+        let fetched = fetch(&http, &server, &locator, 2023, 5).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let fetched = fetch(&http, &server, &locator, 2023, 5).unwrap();
+        assert_eq!(fetched, Fetched::Cached(path));
+        assert_eq!(
+            *http.requests.borrow(),
+            ["http://localhost:1234/2023/day/5/input"]
+        );
+    }
+
+    #[test]
+    fn failures_are_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = InputLocator::new(Some(dir.path().to_owned()));
+        let http = Mock::new(404, "Please don't repeatedly request this endpoint.\n");
+
+        let err = fetch(&http, &server(None), &locator, 2024, 1).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Remote);
+        assert!(http.requests.borrow().is_empty());
+
+        let err = fetch(&http, &server(Some("secret")), &locator, 2024, 1).unwrap_err();
+        assert!(err.message.contains("404"));
+        assert!(locator.find(2024, 1).is_none());
+    }
+
+    #[test]
+    fn only_the_cache_counts() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = InputLocator::new(Some(dir.path().to_owned()));
+        let http = Mock::new(200, "4 5 6\n");
+
+        // A flat input in the directory is found when solving, but isn't where `fetch` stores it
+        std::fs::write(dir.path().join("day05.txt"), "1 2 3\n").unwrap();
+        assert!(locator.find(2023, 5).is_some());
+
+        let path = dir.path().join("2023").join("day05.txt");
+        let fetched = fetch(&http, &server(Some("secret")), &locator, 2023, 5).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "4 5 6\n");
+    }
+}
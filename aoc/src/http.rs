@@ -0,0 +1,85 @@
+use aoc_core::{AocError, ErrorKind};
+
+/// A response of the puzzle server, error statuses are responses as well.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The requests the runner sends to the puzzle server, authenticated with the session cookie.
+///
+/// `Ureq` talks to the real server, tests substitute their own implementation.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, AocError>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, AocError>;
+}
+
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build();
+        Self { agent }
+    }
+
+    fn finish(
+        url: &str,
+        result: Result<ureq::Response, ureq::Error>,
+    ) -> Result<Response, AocError> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => {
+                return Err(AocError::new(
+                    ErrorKind::Remote,
+                    format!("request to `{}` failed: {}", url, e),
+                ))
+            }
+        };
+        let status = response.status();
+        let body = response.into_string().map_err(|e| {
+            AocError::new(
+                ErrorKind::Remote,
+                format!("invalid response from `{}`: {}", url, e),
+            )
+        })?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<Response, AocError> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session));
+        Self::finish(url, request.call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, AocError> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session));
+        Self::finish(url, request.send_form(form))
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{AocError, ErrorKind};
+
+/// Name of the environment variable overriding the input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Finds the puzzle input of a day.
+///
+/// Without an explicit directory the runner looks for `input/<year>/dayNN.txt` and
+/// `year<year>/input/dayNN.txt`. Only an explicit directory may hold the inputs of a single year
+/// as `dayNN.txt`, a default one is shared by all years.
+#[derive(Clone, Debug, Default)]
+pub struct InputLocator {
+    dir: Option<PathBuf>,
+}
+
+impl InputLocator {
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        Self { dir }
+    }
+
+    /// The directory given on the command line or in the environment.
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// All paths the input of a day may be stored at, in order of preference.
+    pub fn candidates(&self, year: u32, day: u32) -> Vec<PathBuf> {
+        let file = format!("day{:0>2}.txt", day);
+        match &self.dir {
+            Some(dir) => vec![dir.join(year.to_string()).join(&file), dir.join(&file)],
+            None => vec![
+                Path::new("input").join(year.to_string()).join(&file),
+                Path::new(&format!("year{}", year))
+                    .join("input")
+                    .join(&file),
+            ],
+        }
+    }
+
+    /// The first candidate that exists.
+    pub fn find(&self, year: u32, day: u32) -> Option<PathBuf> {
+        self.candidates(year, day)
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+
+    /// Where a downloaded input is stored, `<DIR>/<YEAR>/dayNN.txt`.
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir
+            .as_deref()
+            .unwrap_or(Path::new("input"))
+            .join(year.to_string())
+            .join(format!("day{:0>2}.txt", day))
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String, AocError> {
+        let Some(path) = self.find(year, day) else {
+            let candidates = self.candidates(year, day);
+            let tried = candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(AocError::new(
+                ErrorKind::Input,
+                format!("no input of year {} found (tried {})", year, tried),
+            )
+            .with_day(day));
+        };
+        read_path(&path).map_err(|e| e.with_day(day))
+    }
+}
+
+/// Reads an input given on the command line, `-` denotes stdin.
+pub fn read_path(path: &Path) -> Result<String, AocError> {
+    let display = path.display().to_string();
+    if display == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| AocError::input("<stdin>", e))?;
+        return Ok(content);
+    }
+    std::fs::read_to_string(path).map_err(|e| AocError::input(&display, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_year_subdirectory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("2023")).unwrap();
+        std::fs::write(dir.path().join("day05.txt"), "flat").unwrap();
+
+        let locator = InputLocator::new(Some(dir.path().to_owned()));
+        assert_eq!(locator.read(2023, 5).unwrap(), "flat");
+
+        std::fs::write(dir.path().join("2023").join("day05.txt"), "nested").unwrap();
+        assert_eq!(locator.read(2023, 5).unwrap(), "nested");
+    }
+
+    #[test]
+    fn default_directory_is_per_year() {
+        let locator = InputLocator::default();
+        assert!(locator
+            .candidates(2023, 5)
+            .iter()
+            .all(|path| path.to_string_lossy().contains("2023")));
+    }
+
+    #[test]
+    fn missing_input_lists_candidates() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = InputLocator::new(Some(dir.path().to_owned()));
+
+        let err = locator.read(2024, 3).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Input);
+        assert_eq!(err.day, Some(3));
+        assert!(err.message.contains("day03.txt"));
+    }
+}
@@ -0,0 +1,434 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use answers::{Answers, Verification};
+use aoc_core::examples::Example;
+use aoc_core::registry::Registry;
+use aoc_core::{AocError, ErrorKind, Params, Part};
+use clap::{CommandFactory, Parser, Subcommand};
+use input::InputLocator;
+use worker::Solved;
+
+mod all;
+mod answers;
+mod bench;
+mod fetch;
+mod http;
+mod input;
+mod plugins;
+mod render;
+mod report;
+mod scaffold;
+mod submit;
+mod watch;
+mod worker;
+
+/// All days known to the runner
+pub static REGISTRY: LazyLock<Registry> = LazyLock::new(|| {
+    let mut registry = Registry::new();
+    registry.register(year2023::DAYS).register(year2024::DAYS);
+    registry
+});
+
+/// Reads the input of a day, either from `input` or from where `locator` finds it.
+pub fn load(
+    year: u32,
+    day: u32,
+    locator: &InputLocator,
+    input: Option<&PathBuf>,
+) -> Result<String, AocError> {
+    match input {
+        Some(path) => input::read_path(path).map_err(|e| e.with_day(day)),
+        None => locator.read(year, day),
+    }
+}
+
+/// Solutions to Advent of Code
+#[derive(Parser, Debug)]
+#[command(about, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    solve: Option<SolveArgs>,
+
+    /// Directory containing the puzzle inputs, either as `<YEAR>/dayNN.txt` or `dayNN.txt`
+    #[arg(long, value_name = "DIR", env = input::INPUT_DIR_VAR, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// Directory containing solutions built as shared libraries
+    #[arg(long, value_name = "DIR", env = plugins::PLUGINS_DIR_VAR, default_value = plugins::DEFAULT_PLUGINS_DIR, global = true)]
+    plugins: PathBuf,
+
+    /// Compare the answers with the recorded ones instead of printing them
+    #[arg(long, global = true, conflicts_with = "record")]
+    verify: bool,
+
+    /// Store the answers in the answers file instead of printing them
+    #[arg(long, global = true)]
+    record: bool,
+
+    /// Give up on a part after this many seconds, 0 waits forever
+    #[arg(long, value_name = "SECONDS", default_value_t = 300.0, global = true)]
+    timeout: f64,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t, global = true, conflicts_with_all = ["verify", "record"])]
+    format: report::Format,
+
+    /// The file containing the recorded answers
+    #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_ANSWERS_FILE, global = true)]
+    answers: PathBuf,
+}
+
+/// Solves one day, used when no subcommand is given.
+#[derive(clap::Args, Debug)]
+struct SolveArgs {
+    /// The year of the event
+    year: u32,
+
+    /// The day specifying the problem
+    day: u32,
+
+    /// Print the solution to part a
+    #[arg(short = 'a')]
+    solve_a: bool,
+
+    /// Print the solution to part b
+    #[arg(short = 'b')]
+    solve_b: bool,
+
+    /// Read the puzzle input from this file, `-` reads from stdin
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+
+    /// Solve an example file with its parameters, see `year<YEAR>/examples/`
+    #[arg(long, value_name = "PATH", conflicts_with = "input")]
+    example: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. `--param size=6` to solve an example
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+impl SolveArgs {
+    /// The parts selected with `-a` and `-b`, all solved ones if `None`.
+    fn parts(&self) -> Option<&'static [Part]> {
+        match (self.solve_a, self.solve_b) {
+            (true, false) => Some(&[Part::A]),
+            (false, true) => Some(&[Part::B]),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve every registered day of every year
+    All(all::AllArgs),
+
+    /// Measure the run time of the solutions
+    Bench(bench::BenchArgs),
+
+    /// Download the puzzle inputs into the input directory, unless they are already there
+    Fetch(fetch::FetchArgs),
+
+    /// Submit the answer to a part, unless the history tells it can't be right
+    Submit(submit::SubmitArgs),
+
+    /// Add a day from a template, creating the crate of the year if necessary
+    New(scaffold::NewArgs),
+
+    /// Re-run the examples and the input of a day whenever its module, examples or input change
+    Watch(watch::WatchArgs),
+
+    /// Solve a day with the runner and every plugin solving it, side by side
+    Compare(plugins::CompareArgs),
+
+    /// List the days solved by the plugins
+    Plugins,
+
+    /// List the solved days
+    List {
+        /// Only list the days of this year
+        year: Option<u32>,
+    },
+}
+
+/// What to do with the answers of the executed days
+enum Mode {
+    Print,
+    Report(report::Format, Vec<report::Row>),
+    Verify(Answers, Vec<Verification>),
+    Record(Answers),
+}
+
+impl Mode {
+    /// Processes the outcome of solving the `parts` of a day, all solved ones if `None`, returns
+    /// `false` if it failed.
+    fn handle(&mut self, year: u32, day: u32, parts: Option<&[Part]>, solved: Solved) -> bool {
+        let report = &solved.report;
+        match self {
+            Mode::Print if !report.parts.is_empty() => render::console(report),
+            Mode::Print => {}
+            Mode::Report(_, rows) if !report.parts.is_empty() => {
+                rows.extend(report::Row::from_report(year, day, report))
+            }
+            Mode::Report(..) => {}
+            Mode::Verify(answers, verifications) => {
+                // Failures show up as verdicts, each part with its own
+                let parts = parts.unwrap_or_else(|| {
+                    REGISTRY
+                        .get(year, day)
+                        .map_or(&Part::ALL, |entry| entry.parts)
+                });
+                verifications.extend(parts.iter().filter_map(|&part| {
+                    let result = solved.part(part)?;
+                    Some(Verification::check(answers, year, day, part, result))
+                }));
+                return true;
+            }
+            Mode::Record(answers) => {
+                for report in &report.parts {
+                    answers.set(year, day, report.part, &report.answer);
+                }
+            }
+        }
+        for e in &solved.errors {
+            eprintln!("error: {}", e);
+        }
+        solved.errors.is_empty()
+    }
+}
+
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    Params::parse_assignment(s).map_err(|e| e.message)
+}
+
+/// Solves the `parts` of a day, all solved ones if `None`, with its compiled solution or, for a
+/// day only plugins solve, with the first of them. `content` reads the input once the day is known.
+fn run_day(
+    year: u32,
+    day: u32,
+    parts: Option<&[Part]>,
+    params: &Params,
+    timeout: Option<Duration>,
+    content: impl FnOnce() -> Result<String, AocError>,
+) -> Solved {
+    let entry = match REGISTRY.get(year, day) {
+        Ok(entry) => entry,
+        Err(e) => {
+            let Some(variant) = plugins::variant(year, day) else {
+                return Solved::failed(e);
+            };
+            return match content() {
+                Ok(content) => worker::run(
+                    day,
+                    Arc::from(content),
+                    parts.unwrap_or(&variant.parts),
+                    params,
+                    timeout,
+                    |c, p, params, step| variant.steps(c, p, params, step),
+                ),
+                Err(e) => Solved::failed(e),
+            };
+        }
+    };
+    let parts = parts.unwrap_or(entry.parts);
+    match entry.check_parts(parts).and_then(|()| content()) {
+        Ok(content) => worker::run(
+            day,
+            Arc::from(content),
+            parts,
+            params,
+            timeout,
+            |c, p, params, step| entry.steps(c, p, params, step),
+        ),
+        Err(e) => Solved::failed(e),
+    }
+}
+
+/// Solves the `parts` of a day, all solved ones if `None`.
+pub fn solve(
+    year: u32,
+    day: u32,
+    parts: Option<&[Part]>,
+    params: &Params,
+    locator: &InputLocator,
+    input: Option<&PathBuf>,
+    timeout: Option<Duration>,
+) -> Solved {
+    run_day(year, day, parts, params, timeout, || {
+        load(year, day, locator, input)
+    })
+}
+
+/// Solves the `parts` of a day for an example file, `params` override the ones of the example.
+pub fn solve_example(
+    year: u32,
+    day: u32,
+    parts: Option<&[Part]>,
+    params: &Params,
+    path: &Path,
+    timeout: Option<Duration>,
+) -> Solved {
+    let example = input::read_path(path)
+        .map_err(|e| e.with_day(day))
+        .and_then(|content| {
+            let example = Example::parse(&content).map_err(|e| {
+                AocError::new(
+                    ErrorKind::Input,
+                    format!("invalid example `{}`: {}", path.display(), e),
+                )
+                .with_day(day)
+            })?;
+            Ok((
+                example.input.to_owned(),
+                example.params.clone().merge(params),
+            ))
+        });
+    match example {
+        Ok((input, params)) => run_day(year, day, parts, &params, timeout, || Ok(input)),
+        Err(e) => Solved::failed(e),
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    // Loaded once at startup, so every command sees the days the plugins solve
+    let plugin_errors = plugins::init(&args.plugins);
+    if !matches!(args.command, Some(Command::Plugins)) {
+        for e in &plugin_errors {
+            eprintln!("warning: {}", e);
+        }
+    }
+
+    let locator = InputLocator::new(args.input_dir);
+    let timeout = Duration::try_from_secs_f64(args.timeout)
+        .ok()
+        .filter(|timeout| !timeout.is_zero());
+    let load_answers = || {
+        Answers::load(&args.answers).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        })
+    };
+    let mut mode = if args.verify {
+        Mode::Verify(load_answers(), Vec::new())
+    } else if args.record {
+        Mode::Record(load_answers())
+    } else if args.format != report::Format::Text {
+        Mode::Report(args.format, Vec::new())
+    } else {
+        Mode::Print
+    };
+
+    let mut failed = false;
+
+    match args.command {
+        Some(Command::List { year }) => render::list(REGISTRY.days(year)),
+        Some(Command::Compare(compare_args)) => {
+            failed |= !plugins::compare(&compare_args, &locator, timeout);
+        }
+        Some(Command::Plugins) => {
+            render::plugins(plugins::loaded());
+            for e in &plugin_errors {
+                eprintln!("error: {}", e);
+            }
+            failed |= !plugin_errors.is_empty();
+        }
+        Some(Command::Fetch(fetch_args)) => {
+            failed |= !fetch::run(&fetch_args, &http::Ureq::new(), &locator);
+        }
+        Some(Command::Submit(submit_args)) => {
+            failed |= !submit::run(&submit_args, &http::Ureq::new(), &locator, timeout);
+        }
+        Some(Command::New(new_args)) => {
+            failed |= !scaffold::run(&new_args);
+        }
+        Some(Command::Watch(watch_args)) => {
+            failed |= !watch::watch(&watch_args, &locator);
+        }
+        Some(Command::Bench(bench_args)) => {
+            failed |= !bench::bench(&bench_args, &locator, timeout);
+        }
+        Some(Command::All(all_args)) if all_args.parallel => {
+            let days = all::days(all_args.year);
+            let (runs, elapsed) = all::run(&days, &locator, true, timeout);
+            let sequential = all_args
+                .sequential
+                .then(|| all::run(&days, &locator, false, timeout));
+
+            if let Mode::Print = mode {
+                render::runs(&runs, elapsed, sequential.as_ref());
+            }
+            for run in runs {
+                match (&mode, run.solved) {
+                    (Mode::Print, solved) if solved.errors.is_empty() => {}
+                    (_, solved) => failed |= !mode.handle(run.year, run.day, None, solved),
+                }
+            }
+        }
+        Some(Command::All(all_args)) => {
+            for (year, day) in all::days(all_args.year) {
+                if let Mode::Print = mode {
+                    println!("========== {} day {:0>2} ==========", year, day);
+                }
+                let solved = solve(year, day, None, &Params::new(), &locator, None, timeout);
+                failed |= !mode.handle(year, day, None, solved);
+            }
+        }
+        None => {
+            let Some(solve_args) = &args.solve else {
+                Args::command()
+                    .error(
+                        clap::error::ErrorKind::MissingRequiredArgument,
+                        "either a subcommand or <YEAR> <DAY> is required",
+                    )
+                    .exit();
+            };
+            let (year, day, parts) = (solve_args.year, solve_args.day, solve_args.parts());
+            let params = solve_args.params.iter().cloned().collect();
+            let solved = match &solve_args.example {
+                Some(path) => solve_example(year, day, parts, &params, path, timeout),
+                None => solve(
+                    year,
+                    day,
+                    parts,
+                    &params,
+                    &locator,
+                    solve_args.input.as_ref(),
+                    timeout,
+                ),
+            };
+            failed |= !mode.handle(year, day, parts, solved);
+        }
+    }
+
+    match mode {
+        Mode::Print => {}
+        Mode::Report(format, rows) => {
+            if let Some(report) = report::format(format, &rows) {
+                println!("{}", report.trim_end());
+            }
+        }
+        Mode::Verify(_, verifications) => {
+            render::verifications(&verifications);
+            failed |= verifications.iter().any(Verification::is_failure)
+                || !Verification::any_checked(&verifications);
+        }
+        Mode::Record(answers) => {
+            if let Err(e) = answers.save(&args.answers) {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
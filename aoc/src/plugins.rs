@@ -0,0 +1,156 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use aoc_core::plugin::{EntryFn, Variant, SYMBOL};
+use aoc_core::{AocError, ErrorKind, Params};
+use clap::Args;
+use libloading::Library;
+
+use crate::input::InputLocator;
+use crate::worker::Solved;
+use crate::{input, render, worker, REGISTRY};
+
+/// Name of the environment variable overriding the plugins directory
+pub const PLUGINS_DIR_VAR: &str = "AOC_PLUGINS_DIR";
+
+/// Default location of the plugins, relative to the working directory
+pub const DEFAULT_PLUGINS_DIR: &str = "plugins";
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// The year of the event
+    year: u32,
+
+    /// The day specifying the problem
+    day: u32,
+
+    /// Read the puzzle input from this file, `-` reads from stdin
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. `--param size=6` to solve an example
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = crate::parse_param)]
+    params: Vec<(String, String)>,
+}
+
+/// The plugins loaded at startup
+static LOADED: OnceLock<&'static [Variant]> = OnceLock::new();
+
+fn load_library(path: &Path) -> Result<Vec<Variant>, AocError> {
+    let invalid = |message: String| {
+        AocError::new(
+            ErrorKind::Input,
+            format!("invalid plugin `{}`: {}", path.display(), message),
+        )
+    };
+
+    // SAFETY: loading a library runs its initializers, plugins are trusted like the runner itself
+    let library = unsafe { Library::new(path) }.map_err(|e| invalid(e.to_string()))?;
+    let variants = {
+        // SAFETY: `SYMBOL` is exported by `gen_plugin!` with the signature of `EntryFn`
+        let entry =
+            unsafe { library.get::<EntryFn>(SYMBOL) }.map_err(|e| invalid(e.to_string()))?;
+        unsafe { Variant::from_table(entry()) }.map_err(|e| invalid(e.message))?
+    };
+    // The variants point into the library, so it stays loaded until the runner exits
+    std::mem::forget(library);
+    Ok(variants)
+}
+
+/// Loads all plugins in `dir`, a missing directory contains none. Plugins that fail to load are
+/// returned as errors.
+pub fn load(dir: &Path) -> (&'static [Variant], Vec<AocError>) {
+    let mut paths = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut variants = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        match load_library(&path) {
+            Ok(loaded) => variants.extend(loaded),
+            Err(e) => errors.push(e),
+        }
+    }
+    variants.sort_by_key(|v| (v.year, v.day, v.name.clone()));
+    (variants.leak(), errors)
+}
+
+/// Loads the plugins in `dir` for the rest of the run, returns the ones that failed to load.
+pub fn init(dir: &Path) -> Vec<AocError> {
+    let (variants, errors) = load(dir);
+    // Only the first call loads anything, the runner calls this once at startup
+    LOADED.get_or_init(|| variants);
+    errors
+}
+
+/// The plugins loaded by [`init`].
+pub fn loaded() -> &'static [Variant] {
+    LOADED.get().copied().unwrap_or_default()
+}
+
+/// The first plugin solving a day, by the name of the plugin.
+pub fn variant(year: u32, day: u32) -> Option<&'static Variant> {
+    loaded().iter().find(|v| v.year == year && v.day == day)
+}
+
+/// Solves a day with the compiled solution and every plugin for it, returns `false` if any of
+/// them failed.
+pub fn compare(args: &CompareArgs, locator: &InputLocator, timeout: Option<Duration>) -> bool {
+    let (year, day) = (args.year, args.day);
+    let content = match &args.input {
+        Some(path) => input::read_path(path).map_err(|e| e.with_day(day)),
+        None => locator.read(year, day),
+    };
+    let content: Arc<str> = match content {
+        Ok(content) => Arc::from(content),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+
+    let params = args.params.iter().cloned().collect::<Params>();
+    let mut results: Vec<(String, Solved)> = Vec::new();
+    if let Ok(entry) = REGISTRY.get(year, day) {
+        let result = worker::run(
+            day,
+            Arc::clone(&content),
+            entry.parts,
+            &params,
+            timeout,
+            |c, p, params, step| entry.steps(c, p, params, step),
+        );
+        results.push(("builtin".to_owned(), result));
+    }
+    for variant in loaded().iter().filter(|v| v.year == year && v.day == day) {
+        let result = worker::run(
+            day,
+            Arc::clone(&content),
+            &variant.parts,
+            &params,
+            timeout,
+            |c, p, params, step| variant.steps(c, p, params, step),
+        );
+        results.push((variant.name.clone(), result));
+    }
+
+    if results.is_empty() {
+        eprintln!(
+            "error: neither the runner nor a plugin solves {} day {}",
+            year, day
+        );
+        return false;
+    }
+    render::comparison(&results);
+    results.iter().all(|(_, solved)| solved.errors.is_empty())
+}
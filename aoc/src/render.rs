@@ -0,0 +1,345 @@
+use std::time::Duration;
+
+use aoc_core::bench::Stage;
+use aoc_core::plugin::Variant;
+use aoc_core::registry::DayEntry;
+use aoc_core::{AocError, DayReport, ErrorKind, Part};
+
+use crate::all::DayRun;
+use crate::answers::{Verdict, Verification};
+use crate::bench::{change, Baseline, DayBench};
+use crate::worker::Solved;
+
+/// Formats a duration with a unit fitting its magnitude and three significant digits.
+pub fn duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let precision = match value {
+        _ if unit == "ns" => 0,
+        v if v < 10.0 => 2,
+        v if v < 100.0 => 1,
+        _ => 0,
+    };
+    format!("{:.*} {}", precision, value, unit)
+}
+
+/// The status of a failed day in a summary.
+fn status(e: &AocError) -> String {
+    let part = e.part.map(|part| format!(" ({})", part.to_string()));
+    match e.kind {
+        ErrorKind::Input => "no input".to_owned(),
+        ErrorKind::Timeout => format!("TIMEOUT{}", part.unwrap_or_default()),
+        ErrorKind::Panic => format!(
+            "PANIC{}: {}",
+            part.unwrap_or_default(),
+            e.message.trim_start_matches("panicked: ")
+        ),
+        _ => "FAILED".to_owned(),
+    }
+}
+
+/// Prints the report in the human readable format of the runner.
+pub fn console(report: &DayReport) {
+    println!();
+    println!("Parsing ...");
+    println!("... took {}", duration(report.parse_time));
+    for part in &report.parts {
+        println!();
+        println!("Solving part {} ...", part.part.to_string());
+        println!("... took {}", duration(part.solve_time));
+        println!();
+        println!("The Solution is:");
+        println!("{}", part.answer);
+    }
+    println!();
+    println!(
+        "Total: {} (parse {}, solve {})",
+        duration(report.total_time()),
+        duration(report.parse_time),
+        duration(report.solve_time())
+    );
+    println!();
+}
+
+/// Prints a table of all checked parts, followed by a diff of the mismatching ones.
+pub fn verifications(verifications: &[Verification]) {
+    println!(
+        "{:<6} {:<4} {:<5} {:<10} answer",
+        "year", "day", "part", "status"
+    );
+    for v in verifications {
+        let status = match v.verdict {
+            Verdict::Correct => "ok",
+            Verdict::Wrong { .. } => "MISMATCH",
+            Verdict::Unrecorded => "unknown",
+            Verdict::NoInput => "no input",
+            Verdict::MissingInput => "NO INPUT",
+            Verdict::Failed => "FAILED",
+            Verdict::Timeout => "TIMEOUT",
+            Verdict::Panicked => "PANIC",
+        };
+        println!(
+            "{:<6} {:<4} {:<5} {:<10} {}",
+            v.year,
+            v.day,
+            v.part.to_string(),
+            status,
+            v.actual.lines().next().unwrap_or_default()
+        );
+    }
+
+    let failures = verifications
+        .iter()
+        .filter(|v| v.is_failure())
+        .collect::<Vec<_>>();
+    if !Verification::any_checked(verifications) && failures.is_empty() {
+        println!();
+        println!("No recorded answer was checked.");
+        return;
+    }
+    if failures.is_empty() {
+        let correct = verifications
+            .iter()
+            .filter(|v| v.verdict == Verdict::Correct)
+            .count();
+        println!();
+        println!("All {} recorded answers match.", correct);
+        return;
+    }
+
+    println!();
+    println!(
+        "{} of {} parts failed:",
+        failures.len(),
+        verifications.len()
+    );
+    for v in failures {
+        println!();
+        println!("{} day {} part {}", v.year, v.day, v.part.to_string());
+        match &v.verdict {
+            Verdict::Wrong { expected } => {
+                for line in expected.lines() {
+                    println!("- {}", line);
+                }
+                for line in v.actual.lines() {
+                    println!("+ {}", line);
+                }
+            }
+            _ => println!("! {}", v.actual),
+        }
+    }
+}
+
+/// Prints the statistics of all stages of a day, returns `false` if a stage regressed.
+pub fn bench_day(
+    year: u32,
+    result: &DayBench,
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> bool {
+    println!();
+    println!("{} day {:0>2}", year, result.day);
+    println!(
+        "{:<6} {:>10} {:>10} {:>10} {:>10}  baseline",
+        "stage", "min", "median", "mean", "stddev"
+    );
+
+    let mut ok = true;
+    for m in &result.measurements {
+        let stats = m.stats();
+        let comparison = match baseline.and_then(|b| b.get(year, result.day, m.stage)) {
+            None => String::new(),
+            Some(old) => {
+                let change = change(old, stats.median);
+                if change > threshold {
+                    ok = false;
+                    format!("REGRESSION {:+.1}% (was {})", change, duration(old))
+                } else {
+                    format!("{:+.1}%", change)
+                }
+            }
+        };
+        println!(
+            "{:<6} {:>10} {:>10} {:>10} {:>10}  {}",
+            m.stage.to_string(),
+            duration(stats.min),
+            duration(stats.median),
+            duration(stats.mean),
+            duration(stats.stddev),
+            comparison
+        );
+    }
+    ok
+}
+
+/// Prints the medians of all benchmarked days of a year.
+pub fn bench_summary(year: u32, results: &[DayBench]) {
+    let cell = |d: Option<Duration>| d.map(duration).unwrap_or_else(|| "-".to_owned());
+
+    println!();
+    println!("{} summary (medians)", year);
+    println!(
+        "{:<4} {:>10} {:>10} {:>10} {:>10}",
+        "day", "parse", "a", "b", "total"
+    );
+    for result in results {
+        println!(
+            "{:<4} {:>10} {:>10} {:>10} {:>10}",
+            result.day,
+            cell(result.median(Stage::Parse)),
+            cell(result.median(Stage::Solve(Part::A))),
+            cell(result.median(Stage::Solve(Part::B))),
+            duration(result.total())
+        );
+    }
+    println!(
+        "{:<4} {:>10} {:>10} {:>10} {:>10}",
+        "all",
+        "",
+        "",
+        "",
+        duration(results.iter().map(DayBench::total).sum())
+    );
+}
+
+/// Prints one row per day with the timings of a run of `all`, optionally next to the wall times
+/// of a sequential run of the same days.
+pub fn runs(runs: &[DayRun], elapsed: Duration, sequential: Option<&(Vec<DayRun>, Duration)>) {
+    println!(
+        "{:<6} {:<4} {:>10} {:>10} {:>10} {:>10} {:>10}  status",
+        "year", "day", "parse", "a", "b", "wall", "sequential"
+    );
+    for (i, run) in runs.iter().enumerate() {
+        let sequential_time = sequential
+            .map(|(runs, _)| duration(runs[i].wall_time))
+            .unwrap_or_default();
+        let report = &run.solved.report;
+        let status = match run.solved.errors.as_slice() {
+            [] => "ok".to_owned(),
+            errors => errors.iter().map(status).collect::<Vec<_>>().join(", "),
+        };
+        let parse = (!report.parts.is_empty()).then(|| duration(report.parse_time));
+        let part = |part| {
+            report
+                .parts
+                .iter()
+                .find(|r| r.part == part)
+                .map(|r| duration(r.solve_time))
+        };
+        println!(
+            "{:<6} {:<4} {:>10} {:>10} {:>10} {:>10} {:>10}  {}",
+            run.year,
+            run.day,
+            parse.unwrap_or_default(),
+            part(Part::A).unwrap_or_default(),
+            part(Part::B).unwrap_or_default(),
+            duration(run.wall_time),
+            sequential_time,
+            status
+        );
+    }
+
+    let busy = runs.iter().map(|run| run.wall_time).sum::<Duration>();
+    println!();
+    println!(
+        "{} days in {} (sum of wall times {})",
+        runs.len(),
+        duration(elapsed),
+        duration(busy)
+    );
+    if let Some((_, sequential)) = sequential {
+        println!(
+            "sequential run took {}, speedup {:.2}x",
+            duration(*sequential),
+            sequential.as_secs_f64() / elapsed.as_secs_f64()
+        );
+    }
+}
+
+/// Prints the solved days with their titles.
+pub fn list<'a>(days: impl Iterator<Item = &'a DayEntry>) {
+    println!("{:<6} {:<4} {:<6} title", "year", "day", "parts");
+    for entry in days {
+        let parts = entry
+            .parts
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!(
+            "{:<6} {:<4} {:<6} {}",
+            entry.year, entry.day, parts, entry.title
+        );
+    }
+}
+
+/// Prints the days solved by the loaded plugins.
+pub fn plugins(variants: &[Variant]) {
+    println!(
+        "{:<12} {:<6} {:<4} {:<6} title",
+        "plugin", "year", "day", "parts"
+    );
+    for variant in variants {
+        let parts = variant
+            .parts
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!(
+            "{:<12} {:<6} {:<4} {:<6} {}",
+            variant.name, variant.year, variant.day, parts, variant.title
+        );
+    }
+}
+
+/// Prints the timings and answers of several solutions of the same day, answers differing from
+/// the first solution of the part are marked.
+pub fn comparison(results: &[(String, Solved)]) {
+    println!(
+        "{:<12} {:<5} {:>10} {:>10}  answer",
+        "variant", "part", "parse", "solve"
+    );
+    let reference = |part| {
+        results.iter().find_map(|(_, solved)| {
+            solved
+                .report
+                .parts
+                .iter()
+                .find(|r| r.part == part)
+                .map(|r| r.answer.to_string())
+        })
+    };
+    for (name, solved) in results {
+        for part in &solved.report.parts {
+            // Plugins pass answers as text, so compare what is displayed
+            let differs =
+                reference(part.part).is_some_and(|answer| answer != part.answer.to_string());
+            println!(
+                "{:<12} {:<5} {:>10} {:>10}  {}{}",
+                name,
+                part.part.to_string(),
+                duration(solved.report.parse_time),
+                duration(part.solve_time),
+                part.answer.to_string().lines().next().unwrap_or_default(),
+                if differs { "  (DIFFERS)" } else { "" }
+            );
+        }
+        for e in &solved.errors {
+            let part = e.part.map_or("", |part| part.to_string());
+            let status = match e.kind {
+                ErrorKind::Timeout | ErrorKind::Panic => status(e),
+                _ => format!("ERROR: {}", e.message),
+            };
+            println!("{:<12} {:<5} {:>10} {:>10}  {}", name, part, "", "", status);
+        }
+    }
+}
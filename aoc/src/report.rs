@@ -0,0 +1,164 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_core::DayReport;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::render;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable output
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// One solved part in a machine-readable report, times are given in nanoseconds.
+///
+/// Every part of a day carries the parse time of the day, which is shared by all its parts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Row {
+    pub year: u32,
+    pub day: u32,
+    pub part: &'static str,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Row {
+    pub fn from_report(year: u32, day: u32, report: &DayReport) -> Vec<Self> {
+        report
+            .parts
+            .iter()
+            .map(|part| Row {
+                year,
+                day,
+                part: part.part.to_string(),
+                answer: part.answer.to_string(),
+                parse_ns: report.parse_time.as_nanos() as u64,
+                solve_ns: part.solve_time.as_nanos() as u64,
+            })
+            .collect()
+    }
+}
+
+pub fn json(rows: &[Row]) -> String {
+    serde_json::to_string_pretty(rows).expect("rows are always serializable")
+}
+
+pub fn csv(rows: &[Row]) -> String {
+    let mut out = String::from("year,day,part,answer,parse_ns,solve_ns\n");
+    for row in rows {
+        let answer = if row.answer.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", row.answer.replace('"', "\"\""))
+        } else {
+            row.answer.clone()
+        };
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            row.year, row.day, row.part, answer, row.parse_ns, row.solve_ns
+        )
+        .unwrap();
+    }
+    out
+}
+
+pub fn markdown(rows: &[Row]) -> String {
+    let mut out = String::from("| Year | Day | Part | Answer | Parse | Solve |\n");
+    out.push_str("|-----:|----:|:----:|:-------|------:|------:|\n");
+    for row in rows {
+        // Multi-line answers are joined, the table cell can't contain line breaks
+        let answer = row
+            .answer
+            .lines()
+            .map(|line| format!("`{}`", line.replace('|', "\\|")))
+            .collect::<Vec<_>>()
+            .join("<br>");
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} |",
+            row.year,
+            row.day,
+            row.part,
+            answer,
+            render::duration(Duration::from_nanos(row.parse_ns)),
+            render::duration(Duration::from_nanos(row.solve_ns))
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Formats the rows, `Text` is rendered while solving and has no report.
+pub fn format(format: Format, rows: &[Row]) -> Option<String> {
+    match format {
+        Format::Text => None,
+        Format::Json => Some(json(rows)),
+        Format::Csv => Some(csv(rows)),
+        Format::Markdown => Some(markdown(rows)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Answer, Part, SolveReport};
+
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        let report = DayReport {
+            parse_time: Duration::from_nanos(1500),
+            parts: vec![
+                SolveReport {
+                    part: Part::A,
+                    answer: Answer::Int(35),
+                    solve_time: Duration::from_nanos(20),
+                },
+                SolveReport {
+                    part: Part::B,
+                    answer: Answer::Str("#.\n\"#\"".to_owned()),
+                    solve_time: Duration::from_millis(3),
+                },
+            ],
+        };
+        Row::from_report(2023, 5, &report)
+    }
+
+    #[test]
+    fn csv_quotes_answers() {
+        assert_eq!(
+            csv(&rows()),
+            "year,day,part,answer,parse_ns,solve_ns\n\
+             2023,5,a,35,1500,20\n\
+             2023,5,b,\"#.\n\"\"#\"\"\",1500,3000000\n"
+        );
+    }
+
+    #[test]
+    fn json_contains_all_fields() {
+        let value: serde_json::Value = serde_json::from_str(&json(&rows())).unwrap();
+        assert_eq!(value[0]["answer"], "35");
+        assert_eq!(value[0]["parse_ns"], 1500);
+        assert_eq!(value[1]["part"], "b");
+        assert_eq!(value[1]["parse_ns"], 1500);
+        assert_eq!(value[1]["solve_ns"], 3_000_000);
+    }
+
+    #[test]
+    fn markdown_table() {
+        let table = markdown(&rows());
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2], "| 2023 | 5 | a | `35` | 1.50 µs | 20 ns |");
+        assert_eq!(
+            lines[3],
+            "| 2023 | 5 | b | `#.`<br>`\"#\"` | 1.50 µs | 3.00 ms |"
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{AocError, ErrorKind};
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    /// The year of the event, a crate `year<YEAR>` is created if there is none
+    year: u32,
+
+    /// The day to add
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// The title of the puzzle, shown by `list`
+    #[arg(long)]
+    title: Option<String>,
+}
+
+const LIB_TEMPLATE: &str = "\
+use aoc_core::{gen_examples, gen_solve};
+pub use aoc_core::{utils, Answer, AocError, Solution};
+
+gen_solve!(
+    {year};
+);
+
+gen_examples!();
+";
+
+const DAY_TEMPLATE: &str = "\
+use aoc_core::ErrorKind;
+
+use super::*;
+
+pub struct {name};
+impl Solution for {name} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Ok(content.lines().collect())
+    }
+
+    fn part_a<'a>(_input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Err(AocError::new(ErrorKind::NotImplemented, \"part a is not solved yet\"))
+    }
+
+    fn part_b<'a>(_input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Err(AocError::new(ErrorKind::NotImplemented, \"part b is not solved yet\"))
+    }
+}
+";
+
+const EXAMPLE_TEMPLATE: &str = "\
+---
+# a =
+# b =
+---
+";
+
+const MANIFEST_TEMPLATE: &str = "\
+[package]
+name = \"year{year}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[build-dependencies]
+aoc-core = { path = \"../aoc-core\" }
+
+[dependencies]
+aoc-core = { path = \"../aoc-core\" }
+itertools = \"0.12.0\"
+nom = \"7.1.3\"
+";
+
+const BUILD_TEMPLATE: &str = "\
+fn main() {
+    aoc_core::examples::build();
+}
+";
+
+/// The closest directory containing the workspace manifest, starting at `dir`.
+pub fn workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_owned)
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    std::fs::read_to_string(path).map_err(|e| AocError::input(&path.display().to_string(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), AocError> {
+    let display = path.display().to_string();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| AocError::input(&display, e))?;
+    }
+    std::fs::write(path, content).map_err(|e| AocError::input(&display, e))
+}
+
+fn invalid(path: &Path, message: &str) -> AocError {
+    AocError::new(
+        ErrorKind::Input,
+        format!("unable to update `{}`: {}", path.display(), message),
+    )
+}
+
+/// Adds a day to the crate of its year below the workspace `root`, creating and registering the
+/// crate first if necessary, returns the created and modified files.
+pub fn new_day(root: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>, AocError> {
+    let krate = root.join(format!("year{}", year));
+    let module = krate.join("src").join(format!("day{:0>2}.rs", day));
+    if module.exists() {
+        return Err(AocError::new(
+            ErrorKind::Input,
+            format!("`{}` already exists", module.display()),
+        )
+        .with_day(day));
+    }
+
+    let mut changed = Vec::new();
+    if !krate.join("Cargo.toml").exists() {
+        changed.extend(new_year(root, year)?);
+    }
+
+    let name = format!("Day{:0>2}", day);
+    write(&module, &DAY_TEMPLATE.replace("{name}", &name))?;
+    changed.push(module);
+
+    let lib = krate.join("src").join("lib.rs");
+    let content = register_day(&read(&lib)?, day, title).map_err(|e| invalid(&lib, e))?;
+    write(&lib, &content)?;
+    if !changed.contains(&lib) {
+        changed.push(lib);
+    }
+
+    let example = krate
+        .join("examples")
+        .join(format!("day{:0>2}", day))
+        .join("example.txt");
+    if !example.exists() {
+        write(&example, EXAMPLE_TEMPLATE)?;
+        changed.push(example);
+    }
+
+    Ok(changed)
+}
+
+/// Rewrites a file of the workspace to include the crate of a new year
+type Edit = fn(&str, &str) -> Result<String, &'static str>;
+
+/// Creates the crate of a year and wires it to the workspace and the runner.
+fn new_year(root: &Path, year: u32) -> Result<Vec<PathBuf>, AocError> {
+    let krate = root.join(format!("year{}", year));
+    let name = format!("year{}", year);
+    let files = [
+        (
+            krate.join("Cargo.toml"),
+            MANIFEST_TEMPLATE.replace("{year}", &year.to_string()),
+        ),
+        (krate.join("build.rs"), BUILD_TEMPLATE.to_owned()),
+        (
+            krate.join("src").join("lib.rs"),
+            LIB_TEMPLATE.replace("{year}", &year.to_string()),
+        ),
+    ];
+    let mut changed = Vec::new();
+    for (path, content) in files {
+        write(&path, &content)?;
+        changed.push(path);
+    }
+
+    let edits: [(PathBuf, Edit); 3] = [
+        (root.join("Cargo.toml"), add_member),
+        (root.join("aoc").join("Cargo.toml"), add_dependency),
+        (
+            root.join("aoc").join("src").join("main.rs"),
+            add_registration,
+        ),
+    ];
+    for (path, edit) in edits {
+        let content = edit(&read(&path)?, &name).map_err(|e| invalid(&path, e))?;
+        write(&path, &content)?;
+        changed.push(path);
+    }
+    Ok(changed)
+}
+
+/// Inserts the day into the `gen_solve!` list of a year, keeping it sorted.
+fn register_day(lib: &str, day: u32, title: &str) -> Result<String, &'static str> {
+    let start = lib.find("gen_solve!(").ok_or("no `gen_solve!` found")?;
+    let header = start + lib[start..].find(';').ok_or("no year in `gen_solve!`")? + 1;
+    let end = header
+        + lib[header..]
+            .find(");")
+            .ok_or("`gen_solve!` is not closed")?;
+
+    let mut entries = lib[header..end]
+        .lines()
+        .map(|line| line.trim().trim_end_matches(',').to_owned())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    let number = |entry: &str| {
+        entry
+            .strip_prefix('(')
+            .and_then(|entry| entry.split_once(','))
+            .and_then(|(day, _)| day.trim().parse::<u32>().ok())
+    };
+    let mut position = entries.len();
+    for (i, entry) in entries.iter().enumerate() {
+        let Some(other) = number(entry) else {
+            return Err("unexpected entry in `gen_solve!`");
+        };
+        if other == day {
+            return Err("the day is already registered");
+        }
+        if other > day && position == entries.len() {
+            position = i;
+        }
+    }
+    entries.insert(
+        position,
+        format!("({0}, day{0:0>2}, Day{0:0>2}, {1:?})", day, title),
+    );
+
+    let list = entries
+        .iter()
+        .map(|entry| format!("    {}", entry))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    Ok(format!("{}\n{}\n{}", &lib[..header], list, &lib[end..]))
+}
+
+/// Adds a crate to the `members` of the workspace manifest.
+fn add_member(manifest: &str, name: &str) -> Result<String, &'static str> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("no workspace members found")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unclosed workspace members")?;
+    let members = manifest[start + "members = [".len()..end].trim_end();
+    let separator = if members.trim().is_empty() { "" } else { ", " };
+    Ok(format!(
+        "{}members = [{}{}{:?}{}",
+        &manifest[..start],
+        members,
+        separator,
+        name,
+        &manifest[end..]
+    ))
+}
+
+/// Adds the crate of a year to the dependencies of the runner, after the other years.
+fn add_dependency(manifest: &str, name: &str) -> Result<String, &'static str> {
+    let line = format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let position = manifest
+        .match_indices("\nyear")
+        .last()
+        .map(|(i, _)| i + 1 + manifest[i + 1..].find('\n').map_or(0, |end| end + 1))
+        .ok_or("no year dependency found")?;
+    Ok(format!(
+        "{}{}{}",
+        &manifest[..position],
+        line,
+        &manifest[position..]
+    ))
+}
+
+/// Registers the days of a year in the registry of the runner, after the other years.
+fn add_registration(main: &str, name: &str) -> Result<String, &'static str> {
+    let start = main.rfind(".register(").ok_or("no registered year found")?;
+    let end = start + main[start..].find(')').ok_or("unclosed registration")? + 1;
+    // Follow the layout of the chain, either one call per line or all on one line
+    let line_start = main[..start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &main[line_start..start];
+    let separator = if indent.trim().is_empty() {
+        format!("\n{}", indent)
+    } else {
+        String::new()
+    };
+    Ok(format!(
+        "{}{}.register({}::DAYS){}",
+        &main[..end],
+        separator,
+        name,
+        &main[end..]
+    ))
+}
+
+/// Runs `aoc new`, returns `false` if it failed.
+pub fn run(args: &NewArgs) -> bool {
+    let title = args
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {}", args.day));
+    let result = std::env::current_dir()
+        .ok()
+        .and_then(|dir| workspace_root(&dir))
+        .ok_or_else(|| AocError::new(ErrorKind::Input, "not within the workspace"))
+        .and_then(|root| new_day(&root, args.year, args.day, &title));
+
+    match result {
+        Ok(changed) => {
+            for path in changed {
+                println!("{}", path.display());
+            }
+            true
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_days_sorted() {
+        let lib = "use x;\n\ngen_solve!(\n    2023;\n    (1, day01, Day01, \"One\"),\n    (3, day03, Day03, \"Three\")\n);\n";
+        assert_eq!(
+            register_day(lib, 2, "Two \"2\"").unwrap(),
+            "use x;\n\ngen_solve!(\n    2023;\n    (1, day01, Day01, \"One\"),\n    \
+             (2, day02, Day02, \"Two \\\"2\\\"\"),\n    (3, day03, Day03, \"Three\")\n);\n"
+        );
+        assert!(register_day(lib, 3, "Three").is_err());
+
+        let empty = LIB_TEMPLATE.replace("{year}", "2025");
+        assert!(register_day(&empty, 4, "Four")
+            .unwrap()
+            .contains("    2025;\n    (4, day04, Day04, \"Four\")\n);"));
+    }
+
+    #[test]
+    fn bootstraps_a_year() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"aoc\", \"year2024\"]\n",
+            ),
+            (
+                "aoc/Cargo.toml",
+                "[dependencies]\nclap = \"4\"\nyear2024 = { path = \"../year2024\" }\n\n[dev-dependencies]\n",
+            ),
+            (
+                "aoc/src/main.rs",
+                "    registry\n        .register(year2024::DAYS);\n",
+            ),
+        ];
+        for (path, content) in files {
+            write(&root.join(path), content).unwrap();
+        }
+
+        new_day(root, 2025, 7, "Seven").unwrap();
+        new_day(root, 2025, 1, "One").unwrap();
+        assert!(new_day(root, 2025, 1, "One").is_err());
+
+        let read = |path: &str| read(&root.join(path)).unwrap();
+        assert_eq!(
+            read("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"year2024\", \"year2025\"]\n"
+        );
+        assert!(read("aoc/Cargo.toml").contains(
+            "year2024 = { path = \"../year2024\" }\nyear2025 = { path = \"../year2025\" }\n\n"
+        ));
+        assert_eq!(
+            read("aoc/src/main.rs"),
+            "    registry\n        .register(year2024::DAYS)\n        .register(year2025::DAYS);\n"
+        );
+        assert!(read("year2025/src/lib.rs")
+            .contains("    (1, day01, Day01, \"One\"),\n    (7, day07, Day07, \"Seven\")\n"));
+        assert!(read("year2025/src/day07.rs").contains("pub struct Day07;"));
+        assert!(root.join("year2025/examples/day01/example.txt").is_file());
+        assert!(root.join("year2025/build.rs").is_file());
+
+        assert_eq!(
+            add_registration(
+                "r.register(year2023::DAYS).register(year2024::DAYS);",
+                "year2025"
+            )
+            .unwrap(),
+            "r.register(year2023::DAYS).register(year2024::DAYS).register(year2025::DAYS);"
+        );
+    }
+}
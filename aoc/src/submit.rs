@@ -0,0 +1,593 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_core::{Answer, AocError, ErrorKind, Params, Part};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::Server;
+use crate::http::Http;
+use crate::input::InputLocator;
+
+/// Default location of the submission history, relative to the working directory
+pub const DEFAULT_HISTORY_FILE: &str = "submissions.toml";
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// The year of the event
+    year: u32,
+
+    /// The day specifying the problem
+    day: u32,
+
+    /// The part to submit, `a` or `b`
+    #[arg(value_parser = parse_part)]
+    part: Part,
+
+    /// The answer to submit, solves the part if omitted
+    answer: Option<String>,
+
+    #[command(flatten)]
+    server: Server,
+
+    /// The file recording all submissions
+    #[arg(long, value_name = "PATH", default_value = DEFAULT_HISTORY_FILE)]
+    history: PathBuf,
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s {
+        "a" | "A" | "1" => Ok(Part::A),
+        "b" | "B" | "2" => Ok(Part::B),
+        _ => Err(format!("expected `a` or `b`, found `{}`", s)),
+    }
+}
+
+/// How the puzzle server responded to a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The previous submission was too recent, the answer wasn't checked
+    Wait,
+    /// The part was already solved before
+    Solved,
+    /// The response could not be understood
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the answer was checked and found to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wait => "not checked, submitted too recently",
+            Outcome::Solved => "not checked, the part is already solved",
+            Outcome::Unknown => "unknown",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Parses the response to a submission into its outcome and the number of seconds to wait before
+/// the next one.
+pub fn parse_response(body: &str) -> (Outcome, Option<u64>) {
+    let text = plain_text(body);
+    let outcome = if text.contains("answer too recently") {
+        Outcome::Wait
+    } else if text.contains("not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("the right answer") {
+        Outcome::Right
+    } else if text.contains("solving the right level") {
+        Outcome::Solved
+    } else {
+        Outcome::Unknown
+    };
+
+    let wait = match outcome {
+        Outcome::Wait | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow => wait_time(&text),
+        _ => None,
+    };
+    (outcome, wait)
+}
+
+/// The lowercase text of an HTML page.
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.extend(c.to_lowercase()),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The time mentioned in `text` in seconds, either as `1m 23s` or as `one minute`.
+fn wait_time(text: &str) -> Option<u64> {
+    let words = text
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '.' | '(' | ')'))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+    let unit = |word: &str| match word {
+        "s" => Some(1),
+        "m" => Some(60),
+        "h" => Some(3600),
+        _ => match word.trim_end_matches('s') {
+            "second" | "sec" => Some(1),
+            "minute" | "min" => Some(60),
+            "hour" => Some(3600),
+            _ => None,
+        },
+    };
+    let number = |word: &str| match word {
+        "one" => Some(1),
+        _ => word.parse::<u64>().ok(),
+    };
+
+    let mut total = None;
+    for (i, word) in words.iter().enumerate() {
+        let compact = word.char_indices().last().and_then(|(split, _)| {
+            Some(word[..split].parse::<u64>().ok()? * unit(&word[split..])?)
+        });
+        let spelled = words
+            .get(i + 1)
+            .and_then(|next| Some(number(word)? * unit(next)?));
+        if let Some(seconds) = compact.or(spelled) {
+            *total.get_or_insert(0) += seconds;
+        }
+    }
+    total
+}
+
+/// The part of a puzzle a submission is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+}
+
+/// One submission, stored as
+///
+/// ```toml
+/// [[attempt]]
+/// year = 2023
+/// day = 5
+/// part = "a"
+/// answer = "35"
+/// outcome = "too-low"
+/// time = 1701753600
+/// wait_until = 1701753660
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: String,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission
+    pub time: u64,
+    /// Unix time at which the server accepts the next submission
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+impl Attempt {
+    pub fn is_for(&self, puzzle: Puzzle) -> bool {
+        self.year == puzzle.year && self.day == puzzle.day && self.part == puzzle.part.to_string()
+    }
+}
+
+/// All past submissions, used to refuse submissions that can't succeed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history file, a missing file contains no submissions.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(AocError::input(&path.display().to_string(), e)),
+        };
+
+        toml::from_str(&content).map_err(|e| {
+            AocError::new(
+                ErrorKind::Input,
+                format!("invalid submission history `{}`: {}", path.display(), e),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).map_err(|e| {
+            AocError::new(
+                ErrorKind::Input,
+                format!("unable to store the submission history: {}", e),
+            )
+        })?;
+        std::fs::write(path, content).map_err(|e| AocError::input(&path.display().to_string(), e))
+    }
+
+    /// Seconds until the server accepts the next submission to `puzzle`, if it doesn't yet.
+    pub fn cooldown(&self, puzzle: Puzzle, now: u64) -> Option<u64> {
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.is_for(puzzle))
+            .filter_map(|attempt| attempt.wait_until)
+            .max()
+            .filter(|&until| until > now)
+            .map(|until| until - now)
+    }
+
+    /// Fails if submitting `answer` now is pointless: during a cooldown, after the part was
+    /// solved, for an answer already known to be wrong or outside the bounds of earlier ones.
+    pub fn check(&self, puzzle: Puzzle, answer: &str, now: u64) -> Result<(), AocError> {
+        let Puzzle { day, part, .. } = puzzle;
+        let refuse = |message: String| {
+            Err(AocError::new(ErrorKind::Remote, message)
+                .with_day(day)
+                .with_part(part))
+        };
+
+        if let Some(seconds) = self.cooldown(puzzle, now) {
+            return refuse(format!(
+                "refusing to submit during the cooldown, wait {}s",
+                seconds
+            ));
+        }
+
+        // Compare parsed answers, so `050` is known to be as wrong as `50`
+        let parsed = Answer::parse(answer);
+        let value = match parsed {
+            Answer::Int(n) => Some(n),
+            _ => None,
+        };
+        for attempt in self.attempts.iter().filter(|a| a.is_for(puzzle)) {
+            let previous = Answer::parse(&attempt.answer);
+            let bound = match previous {
+                Answer::Int(n) => value.map(|value| (value, n)),
+                _ => None,
+            };
+            match attempt.outcome {
+                Outcome::Right | Outcome::Solved => {
+                    return refuse(format!(
+                        "part {} is already solved with `{}`",
+                        part.to_string(),
+                        attempt.answer
+                    ));
+                }
+                outcome if outcome.is_wrong() && previous == parsed => {
+                    return refuse(format!(
+                        "`{}` was already submitted as `{}`, it is {}",
+                        answer, attempt.answer, outcome
+                    ));
+                }
+                Outcome::TooHigh if bound.is_some_and(|(value, high)| value >= high) => {
+                    return refuse(format!(
+                        "`{}` is too high, `{}` already was",
+                        answer, attempt.answer
+                    ));
+                }
+                Outcome::TooLow if bound.is_some_and(|(value, low)| value <= low) => {
+                    return refuse(format!(
+                        "`{}` is too low, `{}` already was",
+                        answer, attempt.answer
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` to `puzzle` unless `history` tells it's pointless, and records the attempt in
+/// `history`.
+pub fn submit(
+    http: &dyn Http,
+    server: &Server,
+    history: &mut History,
+    puzzle: Puzzle,
+    answer: &str,
+    now: u64,
+) -> Result<Attempt, AocError> {
+    let Puzzle { year, day, part } = puzzle;
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(
+            AocError::new(ErrorKind::Remote, "refusing to submit an empty answer")
+                .with_day(day)
+                .with_part(part),
+        );
+    }
+    history.check(puzzle, answer, now)?;
+
+    let url = format!("{}/answer", server.day_url(year, day));
+    let level = match part {
+        Part::A => "1",
+        Part::B => "2",
+    };
+    let response = http.post_form(
+        &url,
+        server.session()?,
+        &[("level", level), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        return Err(AocError::new(
+            ErrorKind::Remote,
+            format!(
+                "unable to submit the answer of year {} (status {})",
+                year, response.status
+            ),
+        )
+        .with_day(day)
+        .with_part(part));
+    }
+
+    let (outcome, wait) = parse_response(&response.body);
+    let attempt = Attempt {
+        year,
+        day,
+        part: part.to_string().to_owned(),
+        answer: answer.to_owned(),
+        outcome,
+        time: now,
+        wait_until: wait.map(|seconds| now + seconds),
+    };
+    history.attempts.push(attempt.clone());
+    Ok(attempt)
+}
+
+/// Submits the answer selected by `args`, returns `false` unless it was right.
+pub fn run(
+    args: &SubmitArgs,
+    http: &dyn Http,
+    locator: &InputLocator,
+    timeout: Option<Duration>,
+) -> bool {
+    let result = History::load(&args.history).and_then(|mut history| {
+        let answer = match &args.answer {
+            Some(answer) => answer.clone(),
+            None => {
+                let parts = [args.part];
+                let report = crate::solve(
+                    args.year,
+                    args.day,
+                    Some(&parts),
+                    &Params::new(),
+                    locator,
+                    None,
+                    timeout,
+                )
+                .into_result()?;
+                let answer = report.parts[0].answer.to_string();
+                println!(
+                    "{} day {:0>2} part {}: {}",
+                    args.year,
+                    args.day,
+                    args.part.to_string(),
+                    answer
+                );
+                answer
+            }
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let puzzle = Puzzle {
+            year: args.year,
+            day: args.day,
+            part: args.part,
+        };
+        let attempt = submit(http, &args.server, &mut history, puzzle, &answer, now)?;
+        // The answer is submitted at this point, so the outcome is reported even if it can't be
+        // recorded
+        Ok((attempt, history.save(&args.history)))
+    });
+
+    match result {
+        Ok((attempt, saved)) => {
+            print!("`{}` is {}", attempt.answer, attempt.outcome);
+            match attempt.wait_until {
+                Some(until) => println!(
+                    ", wait {}s before the next submission",
+                    until - attempt.time
+                ),
+                None => println!(),
+            }
+            if let Err(e) = &saved {
+                eprintln!(
+                    "error: the submission is not recorded in the history: {}",
+                    e
+                );
+            }
+            attempt.outcome == Outcome::Right && saved.is_ok()
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+    use crate::http::Ureq;
+
+    /// Serves `body` to a single request on a local port, the thread returns the request.
+    fn fake_server(body: &'static str) -> (Server, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = Server {
+            base_url: format!("http://{}", listener.local_addr().unwrap()),
+            session: Some("secret".to_owned()),
+        };
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((key, value)) = line.split_once(':') {
+                    if key.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line.trim().is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (server, handle)
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            parse_response(
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>"
+            ),
+            (Outcome::Right, None)
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p>"),
+            (Outcome::TooHigh, Some(60))
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p>"),
+            (Outcome::Wrong, Some(300))
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p>"),
+            (Outcome::Wait, Some(83))
+        );
+        assert_eq!(
+            parse_response("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"),
+            (Outcome::Solved, None)
+        );
+    }
+
+    #[test]
+    fn history_refuses_pointless_submissions() {
+        let attempt = |answer: &str, outcome, wait_until| Attempt {
+            year: 2023,
+            day: 5,
+            part: "a".to_owned(),
+            answer: answer.to_owned(),
+            outcome,
+            time: 100,
+            wait_until,
+        };
+        let history = History {
+            attempts: vec![
+                attempt("50", Outcome::TooHigh, Some(160)),
+                attempt("10", Outcome::TooLow, None),
+                attempt("abc", Outcome::Wrong, None),
+            ],
+        };
+
+        let a = Puzzle {
+            year: 2023,
+            day: 5,
+            part: Part::A,
+        };
+        let b = Puzzle { part: Part::B, ..a };
+
+        assert_eq!(history.cooldown(a, 130), Some(30));
+        assert_eq!(history.cooldown(b, 130), None);
+        assert!(history.check(a, "20", 130).is_err());
+        assert!(history.check(b, "20", 130).is_ok());
+        assert!(history.check(a, "20", 160).is_ok());
+        assert!(history.check(a, "50", 200).is_err());
+        assert!(history.check(a, "60", 200).is_err());
+        assert!(history.check(a, "9", 200).is_err());
+        assert!(history.check(a, "abc", 200).is_err());
+        assert!(history.check(b, "50", 200).is_ok());
+
+        let content = toml::to_string(&history).unwrap();
+        assert_eq!(toml::from_str::<History>(&content).unwrap(), history);
+    }
+
+    #[test]
+    fn submits_to_server() {
+        let (server, handle) = fake_server(
+            "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article></main>",
+        );
+        let mut history = History::default();
+        let puzzle = Puzzle {
+            year: 2024,
+            day: 7,
+            part: Part::B,
+        };
+
+        let attempt = submit(&Ureq::new(), &server, &mut history, puzzle, "42", 1000).unwrap();
+        assert_eq!(attempt.outcome, Outcome::TooLow);
+        assert_eq!(attempt.wait_until, Some(1060));
+        assert_eq!(history.attempts, [attempt]);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/7/answer "));
+        assert!(request.contains("session=secret"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        // The server is gone, so these must be refused without a request
+        let err = submit(&Ureq::new(), &server, &mut history, puzzle, "43", 1030).unwrap_err();
+        assert!(err.message.contains("cooldown"));
+        let err = submit(&Ureq::new(), &server, &mut history, puzzle, "042", 2000).unwrap_err();
+        assert!(err.message.contains("already submitted"));
+        assert_eq!(history.attempts.len(), 1);
+    }
+}
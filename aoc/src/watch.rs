@@ -0,0 +1,347 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, SystemTime};
+
+use aoc_core::examples::Example;
+use aoc_core::Answer;
+use clap::Args;
+use serde::Deserialize;
+
+use crate::input::InputLocator;
+use crate::{render, scaffold};
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// The year of the event
+    year: u32,
+
+    /// The day specifying the problem
+    day: u32,
+
+    /// How often to look for changes, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    interval: u64,
+}
+
+/// The modification times of the watched files, `None` for missing ones.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// One part as printed by `--format json`.
+#[derive(Clone, Debug, Deserialize)]
+struct Solved {
+    part: String,
+    answer: String,
+    solve_ns: u64,
+}
+
+/// The example files of a day, sorted by name.
+fn examples(root: &Path, year: u32, day: u32) -> Vec<PathBuf> {
+    let dir = root
+        .join(format!("year{}", year))
+        .join("examples")
+        .join(format!("day{:0>2}", day));
+    let mut files = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// The module of the day, its examples and the candidates for its input.
+fn watched(root: &Path, locator: &InputLocator, year: u32, day: u32) -> Vec<PathBuf> {
+    let module = root
+        .join(format!("year{}", year))
+        .join("src")
+        .join(format!("day{:0>2}.rs", day));
+    std::iter::once(module)
+        .chain(examples(root, year, day))
+        .chain(locator.candidates(year, day))
+        .collect()
+}
+
+fn snapshot(paths: Vec<PathBuf>) -> Snapshot {
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// The files that were added, removed or modified between two snapshots.
+fn changes(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let removed = old.keys().filter(|path| !new.contains_key(*path));
+    new.iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(removed)
+        .cloned()
+        .collect()
+}
+
+/// Describes a new answer relative to the one of the previous run.
+fn diff(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => current.to_owned(),
+        Some(previous) if previous == current => format!("{} (unchanged)", current),
+        Some(previous) => format!("{} (was {})", current, previous),
+    }
+}
+
+/// Runs the freshly built runner, returning the solved parts or the error it printed.
+fn run(exe: &Path, args: &[String]) -> Result<Vec<Solved>, String> {
+    let Output {
+        status,
+        stdout,
+        stderr,
+    } = Command::new(exe)
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .map_err(|e| format!("unable to run `{}`: {}", exe.display(), e))?;
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        let message = stderr
+            .lines()
+            .find(|line| !line.trim().is_empty())
+            .unwrap_or("the runner failed");
+        return Err(message.trim_start_matches("error: ").to_owned());
+    }
+    serde_json::from_slice(&stdout).map_err(|e| format!("unexpected output of the runner: {}", e))
+}
+
+/// A message of `cargo build --message-format=json`, only artifacts have a target.
+#[derive(Debug, Deserialize)]
+struct Message {
+    reason: String,
+    target: Option<Target>,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Target {
+    name: String,
+}
+
+/// The binary of the runner among the messages of a build.
+fn executable(messages: &str) -> Option<PathBuf> {
+    messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Message>(line).ok())
+        .filter(|message| {
+            message.reason == "compiler-artifact"
+                && message
+                    .target
+                    .as_ref()
+                    .is_some_and(|target| target.name == env!("CARGO_BIN_NAME"))
+        })
+        .find_map(|message| message.executable)
+}
+
+/// Rebuilds the runner, returns the path of the binary cargo built.
+fn build() -> Option<PathBuf> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()));
+    command.args([
+        "build",
+        "--quiet",
+        "--message-format=json-render-diagnostics",
+        "--package",
+        env!("CARGO_PKG_NAME"),
+    ]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    // Compiler errors are rendered to stderr, the messages on stdout name the binary
+    let output = command.stderr(Stdio::inherit()).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    executable(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Solves the examples of the day and then, if they all pass, the real input.
+fn cycle(
+    root: &Path,
+    locator: &InputLocator,
+    args: &WatchArgs,
+    previous: &mut BTreeMap<String, String>,
+) {
+    let Some(exe) = build() else {
+        println!("build failed");
+        return;
+    };
+    let exe = exe.as_path();
+
+    let mut day = vec![args.year.to_string(), args.day.to_string()];
+    if let Some(dir) = locator.dir() {
+        day.push("--input-dir".to_owned());
+        day.push(dir.display().to_string());
+    }
+    let mut passed = true;
+    for file in examples(root, args.year, args.day) {
+        let name = file.file_name().unwrap().to_string_lossy().into_owned();
+        let content = match std::fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(e) => {
+                println!("{:<20} error: {}", name, e);
+                passed = false;
+                continue;
+            }
+        };
+        let example = match Example::parse(&content) {
+            Ok(example) => example,
+            Err(e) => {
+                println!("{:<20} error: {}", name, e);
+                passed = false;
+                continue;
+            }
+        };
+
+        for &(part, expected) in &example.answers {
+            let mut run_args = day.to_vec();
+            run_args.push(format!("-{}", part.to_string()));
+            run_args.push("--example".to_owned());
+            run_args.push(file.display().to_string());
+            let result = run(exe, &run_args).and_then(|solved| {
+                solved
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| "no answer".to_owned())
+            });
+            let status = match result {
+                Ok(solved) if Answer::parse(&solved.answer) == Answer::parse(expected) => {
+                    format!("ok     {}", solved.answer)
+                }
+                Ok(solved) => {
+                    passed = false;
+                    format!("FAIL   {} (expected {})", solved.answer, expected)
+                }
+                Err(e) => {
+                    passed = false;
+                    format!("ERROR  {}", e)
+                }
+            };
+            println!("{:<20} {}  {}", name, part.to_string(), status);
+        }
+    }
+
+    if !passed {
+        println!("skipping the input, an example failed");
+        return;
+    }
+    match run(exe, &day) {
+        Ok(solved) => {
+            for part in solved {
+                let answer = diff(previous.get(&part.part).map(String::as_str), &part.answer);
+                println!(
+                    "{:<20} {}  {}  [{}]",
+                    "input",
+                    part.part,
+                    answer,
+                    render::duration(Duration::from_nanos(part.solve_ns))
+                );
+                previous.insert(part.part, part.answer);
+            }
+        }
+        Err(e) => println!("{:<20} error: {}", "input", e),
+    }
+}
+
+/// Re-runs a day whenever its module, examples or input change, never returns unless it fails.
+pub fn watch(args: &WatchArgs, locator: &InputLocator) -> bool {
+    let root = std::env::current_dir()
+        .ok()
+        .and_then(|dir| scaffold::workspace_root(&dir));
+    let Some(root) = root else {
+        eprintln!("error: watch needs to run within the workspace");
+        return false;
+    };
+    let module = root
+        .join(format!("year{}", args.year))
+        .join("src")
+        .join(format!("day{:0>2}.rs", args.day));
+    if !module.is_file() {
+        eprintln!("error: `{}` does not exist", module.display());
+        return false;
+    }
+
+    let mut previous = BTreeMap::new();
+    let mut last = Snapshot::new();
+    loop {
+        let current = snapshot(watched(&root, locator, args.year, args.day));
+        let changed = changes(&last, &current);
+        if !changed.is_empty() {
+            if !last.is_empty() {
+                let changed = changed
+                    .iter()
+                    .map(|path| {
+                        path.strip_prefix(&root)
+                            .unwrap_or(path)
+                            .display()
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>();
+                println!();
+                println!("---------- changed: {} ----------", changed.join(", "));
+            }
+            cycle(&root, locator, args, &mut previous);
+            // Edits during the run differ from this snapshot and trigger another run
+            last = current;
+        }
+        std::thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("day01.rs");
+        let missing = dir.path().join("day01.txt");
+        let paths = || vec![file.clone(), missing.clone()];
+
+        std::fs::write(&file, "a").unwrap();
+        let old = snapshot(paths());
+        assert!(changes(&old, &snapshot(paths())).is_empty());
+
+        std::fs::write(&missing, "input").unwrap();
+        let new = snapshot(paths());
+        assert_eq!(changes(&old, &new), vec![missing.clone()]);
+        assert_eq!(changes(&new, &snapshot(vec![file.clone()])), vec![missing]);
+        assert_eq!(changes(&Snapshot::new(), &new).len(), 2);
+    }
+
+    #[test]
+    fn finds_the_built_binary() {
+        let messages = format!(
+            "{}\n{}\n{}\n",
+            r#"{"reason":"compiler-artifact","target":{"name":"aoc_core","kind":["lib"]},"executable":null}"#,
+            r#"{"reason":"compiler-artifact","target":{"name":"aoc","kind":["bin"]},"executable":"/ws/target/debug/aoc"}"#,
+            r#"{"reason":"build-finished","success":true}"#,
+        );
+        assert_eq!(
+            executable(&messages),
+            Some(PathBuf::from("/ws/target/debug/aoc"))
+        );
+        assert_eq!(
+            executable(r#"{"reason":"build-finished","success":true}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn diffs_answers() {
+        assert_eq!(diff(None, "12"), "12");
+        assert_eq!(diff(Some("12"), "12"), "12 (unchanged)");
+        assert_eq!(diff(Some("12"), "13"), "13 (was 12)");
+    }
+}
@@ -0,0 +1,290 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use aoc_core::{Answer, AocError, DayReport, ErrorKind, Params, Part, Step};
+
+/// Stack size of the workers, generous since some solutions recurse deeply
+const STACK_SIZE: usize = 64 << 20;
+
+/// Starts a worker thread named `name`.
+fn spawn(name: String, f: impl FnOnce() + Send + 'static) -> Result<(), AocError> {
+    std::thread::Builder::new()
+        .name(name)
+        .stack_size(STACK_SIZE)
+        .spawn(f)
+        .map(|_| ())
+        .map_err(|e| AocError::new(ErrorKind::Solve, format!("unable to start a worker: {}", e)))
+}
+
+/// Waits at most `timeout` for the next message of a worker.
+fn receive<T>(receiver: &mpsc::Receiver<T>, timeout: Option<Duration>) -> Result<T, AocError> {
+    let gone = || AocError::new(ErrorKind::Panic, "the worker stopped without a result");
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => AocError::new(
+                ErrorKind::Timeout,
+                format!("timed out after {}", crate::render::duration(timeout)),
+            ),
+            mpsc::RecvTimeoutError::Disconnected => gone(),
+        }),
+        None => receiver.recv().map_err(|_| gone()),
+    }
+}
+
+/// Runs `f` on a worker thread named `name`.
+///
+/// A panic is reported as `ErrorKind::Panic`. If `f` doesn't finish within `timeout` the result
+/// is `ErrorKind::Timeout`, the worker can't be stopped and is left running detached until the
+/// process exits.
+pub fn isolate<T, F>(name: String, timeout: Option<Duration>, f: F) -> Result<T, AocError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    spawn(name, move || {
+        let result = catch_unwind(AssertUnwindSafe(f));
+        // Nobody is listening anymore if the worker timed out
+        let _ = sender.send(result.map_err(|payload| AocError::panicked(&*payload)));
+    })?;
+    receive(&receiver, timeout)?
+}
+
+/// The outcome of solving the parts of a day. A part that fails, panics or times out doesn't take
+/// the answers of the other parts with it.
+#[derive(Debug)]
+pub struct Solved {
+    /// The parts that were solved
+    pub report: DayReport,
+    /// Why the other parts weren't solved, an error without a part applies to all of them
+    pub errors: Vec<AocError>,
+}
+
+impl Solved {
+    /// Nothing was solved because of `error`, e.g. the input is missing.
+    pub fn failed(error: AocError) -> Self {
+        Self {
+            report: DayReport {
+                parse_time: Duration::ZERO,
+                parts: Vec::new(),
+            },
+            errors: vec![error],
+        }
+    }
+
+    /// The answer to `part` or the reason it has none, `None` if it wasn't requested.
+    pub fn part(&self, part: Part) -> Option<Result<&Answer, &AocError>> {
+        match self.report.parts.iter().find(|report| report.part == part) {
+            Some(report) => Some(Ok(&report.answer)),
+            None => self
+                .errors
+                .iter()
+                .find(|e| e.part.is_none_or(|p| p == part))
+                .map(Err),
+        }
+    }
+
+    /// The report if every part was solved, the first error otherwise.
+    pub fn into_result(self) -> Result<DayReport, AocError> {
+        match self.errors.into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok(self.report),
+        }
+    }
+}
+
+/// What a worker tells about its progress.
+enum Message {
+    Step(Step),
+    /// The worker is done, with the error if the input couldn't be parsed
+    Finished(Result<(), AocError>),
+    Panicked(AocError),
+}
+
+/// Solves the `parts` of a day with `steps` on a worker thread, which parses the input once.
+///
+/// Parsing and every part may take up to `timeout` each. If a part panics or times out, a new
+/// worker parses the input again and continues with the next part, the reported parse time is the
+/// one of the first worker. A timed out worker can't be stopped and keeps running detached until
+/// the process exits. Every command, `watch` included, solves in a process of its own, so they
+/// don't pile up.
+pub fn run<F>(
+    day: u32,
+    content: Arc<str>,
+    parts: &[Part],
+    params: &Params,
+    timeout: Option<Duration>,
+    steps: F,
+) -> Solved
+where
+    F: Fn(&str, &[Part], &Params, &mut dyn FnMut(Step)) -> Result<(), AocError>
+        + Copy
+        + Send
+        + 'static,
+{
+    let mut solved = Solved {
+        report: DayReport {
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        },
+        errors: Vec::new(),
+    };
+    let mut parsed = false;
+    let mut remaining = parts;
+    while let Some(&next) = remaining.first() {
+        let (sender, receiver) = mpsc::channel();
+        let content = Arc::clone(&content);
+        let params = params.clone();
+        let todo = remaining.to_vec();
+        let spawned = spawn(
+            format!("day {:0>2} part {}", day, next.to_string()),
+            move || {
+                let result = catch_unwind(AssertUnwindSafe(|| {
+                    // Nobody is listening anymore if the worker timed out
+                    steps(&content, &todo, &params, &mut |step| {
+                        let _ = sender.send(Message::Step(step));
+                    })
+                }));
+                let _ = sender.send(match result {
+                    Ok(result) => Message::Finished(result),
+                    Err(payload) => Message::Panicked(AocError::panicked(&*payload)),
+                });
+            },
+        );
+        if let Err(e) = spawned {
+            solved.errors.push(e.with_day(day));
+            break;
+        }
+
+        // The worker has parsed the input and is solving the first of the remaining parts
+        let mut solving = false;
+        loop {
+            let error = match receive(&receiver, timeout) {
+                Ok(Message::Step(Step::Parsed(parse_time))) => {
+                    if !parsed {
+                        solved.report.parse_time = parse_time;
+                        parsed = true;
+                    }
+                    solving = true;
+                    continue;
+                }
+                Ok(Message::Step(Step::Solved(result))) => {
+                    match result {
+                        Ok(report) => solved.report.parts.push(report),
+                        Err(e) => solved.errors.push(e.with_day(day)),
+                    }
+                    remaining = &remaining[1..];
+                    continue;
+                }
+                Ok(Message::Finished(Ok(()))) => return solved,
+                Ok(Message::Finished(Err(e))) => e,
+                Ok(Message::Panicked(e)) | Err(e) => e,
+            };
+            match remaining.first() {
+                Some(&part) if solving => {
+                    solved.errors.push(error.with_day(day).with_part(part));
+                    remaining = &remaining[1..];
+                    break;
+                }
+                // Parsing failed, so did every part
+                _ => {
+                    solved.errors.push(error.with_day(day));
+                    return solved;
+                }
+            }
+        }
+    }
+    solved
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Answer, Solution};
+
+    use super::*;
+
+    struct Faulty;
+    impl Solution for Faulty {
+        type Input<'a> = u64;
+
+        fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+            content
+                .trim()
+                .parse()
+                .map_err(|_| AocError::parse("not a number"))
+        }
+
+        fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            let divisors = [0, 1, 2];
+            Ok((input / divisors[*input as usize]).into())
+        }
+
+        fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+            std::thread::sleep(Duration::from_secs(*input));
+            Ok((*input).into())
+        }
+    }
+
+    fn solve(content: &str, parts: &[Part]) -> Solved {
+        let timeout = Some(Duration::from_millis(200));
+        run(
+            3,
+            Arc::from(content),
+            parts,
+            &Params::new(),
+            timeout,
+            Faulty::steps,
+        )
+    }
+
+    #[test]
+    fn isolates_panics() {
+        let err = solve("0", &[Part::A]).into_result().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Panic);
+        assert_eq!((err.day, err.part), (Some(3), Some(Part::A)));
+        assert!(err.message.contains("divide by zero"));
+
+        let report = solve("2", &[Part::A]).into_result().unwrap();
+        assert_eq!(report.parts[0].answer, Answer::Int(1));
+    }
+
+    #[test]
+    fn times_out() {
+        let report = solve("0", &[Part::B]).into_result().unwrap();
+        assert_eq!(report.parts[0].answer, Answer::Int(0));
+
+        let err = solve("1", &[Part::B]).into_result().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Timeout);
+        assert_eq!(err.part, Some(Part::B));
+    }
+
+    #[test]
+    fn failures_are_per_part() {
+        let solved = solve("1", &Part::ALL);
+        assert_eq!(solved.part(Part::A).unwrap().unwrap(), &Answer::Int(1));
+        let err = solved.part(Part::B).unwrap().unwrap_err();
+        assert_eq!(
+            (err.kind.clone(), err.part),
+            (ErrorKind::Timeout, Some(Part::B))
+        );
+
+        // The second part is solved by a new worker after the first one panicked
+        let solved = solve("0", &Part::ALL);
+        let err = solved.part(Part::A).unwrap().unwrap_err();
+        assert_eq!(
+            (err.kind.clone(), err.part),
+            (ErrorKind::Panic, Some(Part::A))
+        );
+        assert_eq!(solved.part(Part::B).unwrap().unwrap(), &Answer::Int(0));
+
+        let solved = solve("x", &Part::ALL);
+        assert_eq!(solved.errors.len(), 1);
+        for part in Part::ALL {
+            assert_eq!(
+                solved.part(part).unwrap().unwrap_err().kind,
+                ErrorKind::Parse
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! An alternative solution of 2024 day 1, loaded by the runner as a plugin.
+//!
+//! Build it with `cargo build -p plugin-example --release`, copy the library from `target/release`
+//! into the plugins directory and compare it with `aoc compare 2024 1`.
+
+use aoc_core::{gen_plugin, gen_test, number, Answer, AocError, Solution};
+
+gen_plugin!("merge"; (2024, 1, Day01, "Historian Hysteria"));
+
+/// Both lists sorted, part b counts the occurrences by merging them instead of hashing.
+pub struct Day01;
+impl Solution for Day01 {
+    type Input<'a> = (Vec<i64>, Vec<i64>);
+
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for line in content.lines() {
+            let Some((a, b)) = line.split_once(char::is_whitespace) else {
+                return Err(AocError::parse_at(content, line, "expected two numbers"));
+            };
+            left.push(number::<i64>(content, a)?);
+            right.push(number::<i64>(content, b)?);
+        }
+        left.sort_unstable();
+        right.sort_unstable();
+        Ok((left, right))
+    }
+
+    fn part_a<'a>((left, right): &Self::Input<'a>) -> Result<Answer, AocError> {
+        let sum = left
+            .iter()
+            .zip(right)
+            .map(|(a, b)| (a - b).abs())
+            .sum::<i64>();
+        Ok(sum.into())
+    }
+
+    fn part_b<'a>((left, right): &Self::Input<'a>) -> Result<Answer, AocError> {
+        let mut sum = 0;
+        let mut j = 0;
+        for &a in left {
+            while j < right.len() && right[j] < a {
+                j += 1;
+            }
+            sum += a * right[j..].iter().take_while(|&&b| b == a).count() as i64;
+        }
+        Ok(sum.into())
+    }
+}
+
+gen_test!(
+    a,
+    Day01,
+    r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+    "11"
+);
+
+gen_test!(
+    b,
+    Day01,
+    r"3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+    "31"
+);
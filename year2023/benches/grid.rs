@@ -0,0 +1,264 @@
+//! Timings of the days spending most of their time accessing `utils::Grid`, on generated inputs
+//! of the size of the real ones, and of the game of life on `Grid` next to the same game on
+//! nested `Vec<Vec<_>>` rows. Run with `cargo bench -p year2023 --bench grid`.
+
+use aoc_core::bench::{measure, BenchConfig, Stats};
+use aoc_core::{Params, Part};
+use year2023::fun::{Cell, GameOfLife};
+use year2023::DAYS;
+
+const CONFIG: BenchConfig = BenchConfig {
+    warmup: 2,
+    samples: 10,
+};
+
+/// A deterministic pseudo random generator, the inputs must not change between runs.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, n: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
+
+fn generate(size: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    (0..size)
+        .map(|y| (0..size).map(|x| cell(x, y)).collect::<String>() + "\n")
+        .collect()
+}
+
+/// The game of life on nested rows, the layout `Grid` had before it stored a single `Vec`. Apart
+/// from the layout this is a copy of the old `Grid` and `GameOfLife::step`: the same wrapped
+/// accesses, the same neighbour iterator and the same rules.
+struct NestedLife {
+    rows: Vec<Vec<Cell>>,
+    width: isize,
+    height: isize,
+    wrapping: bool,
+}
+
+const NEIGHBOR_ORDER: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+struct NestedNeighbors<'a> {
+    life: &'a NestedLife,
+    x: isize,
+    y: isize,
+    current: usize,
+    relevant: u8,
+}
+
+impl<'a> Iterator for NestedNeighbors<'a> {
+    type Item = ((isize, isize), &'a Cell);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current < 8 && (self.relevant >> self.current) & 1 == 0 {
+            self.current += 1;
+        }
+
+        if self.current >= 8 {
+            return None;
+        }
+
+        let x = self.x + NEIGHBOR_ORDER[self.current].0;
+        let y = self.y + NEIGHBOR_ORDER[self.current].1;
+
+        let res = Some(((x, y), &self.life[(x, y)]));
+        self.current += 1;
+        res
+    }
+}
+
+impl NestedLife {
+    fn new(board: &str) -> Self {
+        let rows: Vec<Vec<Cell>> = board
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| if c == 'x' { Cell::Alive } else { Cell::Dead })
+                    .collect()
+            })
+            .collect();
+        Self::from_rows(rows)
+    }
+
+    fn from_rows(rows: Vec<Vec<Cell>>) -> Self {
+        let (width, height) = (rows[0].len() as isize, rows.len() as isize);
+        Self {
+            rows,
+            width,
+            height,
+            wrapping: true,
+        }
+    }
+
+    fn get(&self, x: isize, y: isize) -> Option<&Cell> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            None
+        } else {
+            // SAFETY: the coordinates were just checked against the board.
+            unsafe {
+                Some(
+                    self.rows
+                        .get_unchecked(y as usize)
+                        .get_unchecked(x as usize),
+                )
+            }
+        }
+    }
+
+    fn get_wrapped(&self, x: isize, y: isize) -> &Cell {
+        let x = x.rem_euclid(self.width);
+        let y = y.rem_euclid(self.height);
+
+        // SAFETY: `rem_euclid` keeps both coordinates inside the board, as in the old `Grid`.
+        unsafe {
+            self.rows
+                .get_unchecked(y as usize)
+                .get_unchecked(x as usize)
+        }
+    }
+
+    #[inline(always)]
+    fn relevant_neighbors(&self, x: isize, y: isize, mut relevant: u8) -> u8 {
+        if !self.wrapping {
+            for (i, offset) in NEIGHBOR_ORDER.iter().enumerate() {
+                let x = x + offset.0;
+                let y = y + offset.1;
+
+                if x < 0 || y < 0 || x >= self.width || y >= self.height {
+                    relevant &= 0xFF ^ 1 << i;
+                }
+            }
+        }
+        relevant
+    }
+
+    fn neighbors8(&self, x: isize, y: isize) -> NestedNeighbors<'_> {
+        let relevant = self.relevant_neighbors(x, y, 0xFF);
+
+        NestedNeighbors {
+            life: self,
+            x,
+            y,
+            current: 0,
+            relevant,
+        }
+    }
+
+    fn step(&mut self) {
+        let mut result = Vec::with_capacity(self.height as usize);
+
+        for y in 0..self.height {
+            let mut row = Vec::with_capacity(self.width as usize);
+            for x in 0..self.width {
+                let count = self
+                    .neighbors8(x, y)
+                    .filter(|(_, &x)| x == Cell::Alive)
+                    .count();
+
+                let cell = match count {
+                    ..=1 => Cell::Dead,
+                    2 => self[(x, y)],
+                    3 => Cell::Alive,
+                    4.. => Cell::Dead,
+                };
+                row.push(cell);
+            }
+            result.push(row)
+        }
+
+        *self = Self::from_rows(result);
+    }
+}
+
+impl std::ops::Index<(isize, isize)> for NestedLife {
+    type Output = Cell;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        if self.wrapping {
+            self.get_wrapped(x, y)
+        } else {
+            self.get(x, y).unwrap()
+        }
+    }
+}
+
+impl std::fmt::Display for NestedLife {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.rows {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn print(name: &str, stage: &str, stats: Stats) {
+    println!(
+        "{:<14} {:<6} median {:>12?}  min {:>12?}",
+        name, stage, stats.median, stats.min
+    );
+}
+
+fn day(day: u32, content: &str, parts: &[Part]) {
+    let entry = DAYS.iter().find(|entry| entry.day == day).unwrap();
+    let name = format!("{} day {}", entry.year, entry.day);
+    for m in entry.bench(content, parts, &Params::new(), CONFIG).unwrap() {
+        print(&name, m.stage.to_string(), m.stats());
+    }
+}
+
+fn main() {
+    let mut rng = Lcg(17);
+    let blocks = generate(141, |_, _| char::from(b'1' + rng.next(9) as u8));
+    day(17, &blocks, &Part::ALL);
+
+    let mut rng = Lcg(21);
+    let garden = generate(131, |x, y| match (x, y) {
+        (65, 65) => 'S',
+        (65, _) | (_, 65) => '.',
+        _ if rng.next(10) == 0 => '#',
+        _ => '.',
+    });
+    day(21, &garden, &[Part::A]);
+
+    let mut rng = Lcg(1);
+    let board = generate(256, |_, _| if rng.next(3) == 0 { 'x' } else { '.' });
+    let samples = measure(CONFIG, || {
+        let mut game = GameOfLife::new(&board).unwrap();
+        for _ in 0..20 {
+            game.step();
+        }
+    });
+    print("game of life", "20", Stats::from_samples(&samples));
+
+    // The baseline has to play the same game
+    let (mut game, mut nested) = (GameOfLife::new(&board).unwrap(), NestedLife::new(&board));
+    for _ in 0..20 {
+        game.step();
+        nested.step();
+    }
+    assert_eq!(game.to_string(), nested.to_string());
+    let samples = measure(CONFIG, || {
+        let mut game = NestedLife::new(&board);
+        for _ in 0..20 {
+            game.step();
+        }
+    });
+    print("nested rows", "20", Stats::from_samples(&samples));
+}
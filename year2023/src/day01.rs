@@ -4,11 +4,11 @@ pub struct Day01;
 impl Solution for Day01 {
     type Input<'a> = Vec<&'a str>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        content.lines().collect()
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Ok(content.lines().collect())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let line_parser = parsing::first_and_last(parsing::explicit_digit);
 
         let sum: u32 = input
@@ -17,10 +17,10 @@ impl Solution for Day01 {
             .filter_map(line_parser)
             .map(|(a, b)| 10 * a + b)
             .sum();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let line_parser = parsing::first_and_last(parsing::digit);
 
         let sum: u32 = input
@@ -29,7 +29,7 @@ impl Solution for Day01 {
             .filter_map(line_parser)
             .map(|(a, b)| 10 * a + b)
             .sum();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
@@ -24,11 +24,11 @@ pub struct Day02;
 impl Solution for Day02 {
     type Input<'a> = Vec<Game>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut sum = 0;
 
         'outer: for game in input {
@@ -40,10 +40,10 @@ impl Solution for Day02 {
             sum += game.id;
         }
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut sum = 0u64;
 
         for game in input {
@@ -59,7 +59,7 @@ impl Solution for Day02 {
             sum += (max_red * max_blue * max_green) as u64;
         }
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
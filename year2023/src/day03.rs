@@ -15,16 +15,10 @@ pub enum Entry {
 
 impl Entry {
     fn is_symbol(&self) -> bool {
-        match self {
-            Entry::Symbol(_) => true,
-            _ => false,
-        }
+        matches!(self, Entry::Symbol(_))
     }
     fn is_digit(&self) -> bool {
-        match self {
-            Entry::Digit(_) => true,
-            _ => false,
-        }
+        matches!(self, Entry::Digit(_))
     }
 }
 
@@ -32,11 +26,11 @@ pub struct Day03;
 impl Solution for Day03 {
     type Input<'a> = Grid<Entry>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(grid: &Self::Input<'a>) -> String {
+    fn part_a<'a>(grid: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut current = 0;
         let mut next_to_symbol = false;
         let mut sum = 0;
@@ -57,10 +51,10 @@ impl Solution for Day03 {
             }
         }
 
-        format!("{:#?}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(grid: &Self::Input<'a>) -> String {
+    fn part_b<'a>(grid: &Self::Input<'a>) -> Result<Answer, AocError> {
         fn find_number(
             grid: &Grid<Entry>,
             mut x: isize,
@@ -72,7 +66,7 @@ impl Solution for Day03 {
                 return None;
             }
 
-            while &grid.get(x - 1, y).map(Entry::is_digit) == &Some(true) {
+            while grid.get(x - 1, y).map(Entry::is_digit) == Some(true) {
                 x -= 1;
             }
 
@@ -117,7 +111,7 @@ impl Solution for Day03 {
             })
             .sum::<u32>();
 
-        format!("{:#?}", sum)
+        Ok(sum.into())
     }
 }
 
@@ -125,15 +119,17 @@ mod parsing {
     use super::Entry;
     use crate::utils::Grid;
 
-    pub fn parse(input: &str) -> Grid<Entry> {
-        Grid::parse(input, |c| {
-            if c.is_digit(10) {
-                Entry::Digit(c.to_digit(10).unwrap())
+    use aoc_core::AocError;
+
+    pub fn parse(input: &str) -> Result<Grid<Entry>, AocError> {
+        Grid::try_parse(input, |c| {
+            Some(if let Some(digit) = c.to_digit(10) {
+                Entry::Digit(digit)
             } else if c != '.' {
                 Entry::Symbol(c)
             } else {
                 Entry::None
-            }
+            })
         })
     }
 }
@@ -29,18 +29,16 @@ pub struct Day04;
 impl Solution for Day04 {
     type Input<'a> = Vec<usize>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         // Actually, the first and second part do not care about the card. The only relevant information is the number of matches.
         // Therefore, it is ok to discard any further information while parsing the input.
-        parsing::parse(content)
-            .unwrap()
-            .1
+        Ok(finish(content, parsing::parse(content))?
             .into_iter()
             .map(|card| card.matches())
-            .collect()
+            .collect())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum = input
             .iter()
             .map(|&matches| {
@@ -52,10 +50,10 @@ impl Solution for Day04 {
             })
             .sum::<usize>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         // We store a list of tuples containing the number of matches a card has together with the amount of copies that we have.
         let mut cards = input
             .iter()
@@ -64,14 +62,14 @@ impl Solution for Day04 {
 
         for i in 0..cards.len() {
             let (matches, amount) = cards[i];
-            for j in (i + 1)..=(i + matches) {
-                cards[j].1 += amount;
+            for card in &mut cards[(i + 1)..=(i + matches)] {
+                card.1 += amount;
             }
         }
 
         let sum = cards.iter().map(|(_, amount)| *amount).sum::<usize>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
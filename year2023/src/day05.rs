@@ -33,7 +33,7 @@ impl<'a> Map<'a> {
             }
         }
 
-        cut_input.sort_by(|a, b| a.start.cmp(&b.start));
+        cut_input.sort_by_key(|a| a.start);
         cut_input.push(u64::MAX..u64::MAX);
 
         let mut last_x = x.start;
@@ -59,11 +59,11 @@ pub struct Day05;
 impl Solution for Day05 {
     type Input<'a> = (Vec<u64>, Vec<Map<'a>>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let closest = input
             .0
             .iter()
@@ -77,10 +77,10 @@ impl Solution for Day05 {
             .min()
             .unwrap();
 
-        format!("{}", closest)
+        Ok(closest.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let xs = input
             .0
             .iter()
@@ -94,8 +94,7 @@ impl Solution for Day05 {
             .fold(xs, |acc, map| {
                 acc.iter()
                     .cloned()
-                    .map(|x| map.apply_range(x))
-                    .flatten()
+                    .flat_map(|x| map.apply_range(x))
                     .collect_vec()
             })
             .iter()
@@ -103,7 +102,7 @@ impl Solution for Day05 {
             .min()
             .unwrap();
 
-        format!("{}", closest)
+        Ok(closest.into())
     }
 }
 
@@ -123,7 +122,7 @@ mod parsing {
             .parse(input)
     }
 
-    fn parse_map(input: &str) -> IResult<&str, Map> {
+    fn parse_map(input: &str) -> IResult<&str, Map<'_>> {
         separated_pair(
             tuple((alpha1, tag("-to-"), alpha1, tag(" map:"))).map(|(from, _, to, _)| (from, to)),
             line_ending,
@@ -143,7 +142,7 @@ mod parsing {
             .parse(input)
     }
 
-    pub(super) fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<Map>)> {
+    pub(super) fn parse(input: &str) -> IResult<&str, (Vec<u64>, Vec<Map<'_>>)> {
         separated_pair(
             parse_seeds,
             tuple((line_ending, line_ending)),
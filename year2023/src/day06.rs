@@ -1,6 +1,8 @@
-use super::*;
+use aoc_core::number;
 use num_integer::Roots;
 
+use super::*;
+
 // This whole "parse once, use twice"-idea falls apart today
 
 fn possibilities_to_win(time: u64, distance: u64) -> u64 {
@@ -10,38 +12,45 @@ fn possibilities_to_win(time: u64, distance: u64) -> u64 {
     b - a + 1
 }
 
+/// The number written with the digits of all `parts`, ignoring the spaces between them.
+fn joined(parts: &[&str]) -> Result<u64, AocError> {
+    let digits = parts.concat();
+    digits
+        .parse()
+        .map_err(|_| AocError::parse(format!("`{}` is too large", digits)))
+}
+
 pub struct Day06;
 impl Solution for Day06 {
-    type Input<'a> = Vec<(&'a str, &'a str)>;
+    /// The races, and the single race read without the spaces.
+    type Input<'a> = (Vec<(u64, u64)>, (u64, u64));
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let races = finish(content, parsing::parse(content))?;
+        let (times, distances): (Vec<_>, Vec<_>) = races.iter().copied().unzip();
+        let single = (joined(&times)?, joined(&distances)?);
+        let races = races
+            .into_iter()
+            .map(|(time, distance)| Ok((number(content, time)?, number(content, distance)?)))
+            .collect::<Result<_, AocError>>()?;
+        Ok((races, single))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let p = input
+            .0
             .iter()
-            .map(|(time, distance)| (time.parse().unwrap(), distance.parse().unwrap()))
-            .map(|(time, distance)| possibilities_to_win(time, distance))
+            .map(|&(time, distance)| possibilities_to_win(time, distance))
             .product::<u64>();
 
-        format!("{}", p)
+        Ok(p.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let (time, distance) = input.iter().fold(
-            (String::new(), String::new()),
-            |(mut acc_x, mut acc_y), (x, y)| {
-                acc_x.push_str(x);
-                acc_y.push_str(y);
-                (acc_x, acc_y)
-            },
-        );
-        let time = time.parse().unwrap();
-        let distance = distance.parse().unwrap();
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (time, distance) = input.1;
 
         let p = possibilities_to_win(time, distance);
-        format!("{}", p)
+        Ok(p.into())
     }
 }
 
@@ -60,7 +69,7 @@ mod parsing {
             line_ending,
             tuple((tag("Distance:"), space1, separated_list1(space1, digit1))),
         )
-        .map(|(a, b)| a.2.into_iter().zip(b.2.into_iter()).collect())
+        .map(|(a, b)| a.2.into_iter().zip(b.2).collect())
         .parse(input)
     }
 }
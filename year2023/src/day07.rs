@@ -15,10 +15,10 @@ impl Card {
     fn index(&self) -> usize {
         match *self {
             Card::Number(d) => (d - 2) as usize,
-            Card::Jack => 9 as usize,
-            Card::Queen => 10 as usize,
-            Card::King => 11 as usize,
-            Card::Ace => 12 as usize,
+            Card::Jack => 9_usize,
+            Card::Queen => 10_usize,
+            Card::King => 11_usize,
+            Card::Ace => 12_usize,
         }
     }
 }
@@ -98,7 +98,7 @@ impl Card {
             Card::Number(2),
         ];
 
-        let mut cards = cards.clone();
+        let mut cards = *cards;
         let mut max_type = Type::HighCard;
         for card in cards_excepts_joker {
             cards[pos] = card;
@@ -148,11 +148,11 @@ pub struct Day07;
 impl Solution for Day07 {
     type Input<'a> = Vec<([Card; 5], u32)>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut hand = input.clone();
 
         hand.sort_by(|(x, _), (y, _)| Card::compare_hands(x, y));
@@ -163,10 +163,10 @@ impl Solution for Day07 {
             .map(|(i, (_, bid))| (i as u64 + 1) * (*bid as u64))
             .sum();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut hand = input.clone();
 
         hand.sort_by(|(x, _), (y, _)| Card::compare_hands_joker(x, y));
@@ -177,7 +177,7 @@ impl Solution for Day07 {
             .map(|(i, (_, bid))| (i as u64 + 1) * (*bid as u64))
             .sum();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
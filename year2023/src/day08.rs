@@ -78,7 +78,7 @@ impl Cycle {
 
         let accepted_start = Vec::from(&accepted[..accepted_start_length]);
         let accepted_cycle = Vec::from_iter(
-            (&accepted[accepted_start_length..])
+            accepted[accepted_start_length..]
                 .iter()
                 .map(|&x| x - offset),
         );
@@ -144,7 +144,7 @@ impl Cycle {
             .filter_map(|(&x1, &x2)| {
                 let a1 = (x1 + self.offset).rem_euclid(self.length);
                 let a2 = (x2 + other.offset).rem_euclid(other.length);
-                if a1 % d as u64 != 0 || a2 % d as u64 != 0 {
+                if !a1.is_multiple_of(d as u64) || !a2.is_multiple_of(d as u64) {
                     return None;
                 }
                 let b1 = a1 / d as u64;
@@ -172,11 +172,11 @@ pub struct Day08;
 impl Solution for Day08 {
     type Input<'a> = (Vec<Direction>, HashMap<u32, Node>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut current = Node::index("AAA");
         let target = Node::index("ZZZ");
 
@@ -184,15 +184,15 @@ impl Solution for Day08 {
 
         for (step, direction) in directions.enumerate() {
             if current == target {
-                return format!("{}", step);
+                return Ok(step.into());
             }
             current = input.1[&current].get(*direction);
         }
 
-        unreachable!()
+        Err(AocError::solve("there are no directions to follow"))
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let cycles = input
             .1
             .keys()
@@ -205,7 +205,7 @@ impl Solution for Day08 {
             .reduce(|x, y| Cycle::union(&x, &y))
             .unwrap();
 
-        format!("{}", union_cycle.first_accepted().unwrap())
+        Ok(union_cycle.first_accepted().unwrap().into())
     }
 }
 
@@ -259,7 +259,7 @@ mod parsing {
         many1(direction)
             .and(many1(line_ending))
             .and(separated_list1(line_ending, node))
-            .map(|((instr, _), nodes)| (instr, HashMap::from_iter(nodes.into_iter())))
+            .map(|((instr, _), nodes)| (instr, HashMap::from_iter(nodes)))
             .parse(input)
     }
 }
@@ -1,7 +1,7 @@
 use super::*;
 
 fn extrapolate_right(values: &mut [i64]) -> i64 {
-    if values.len() == 0 {
+    if values.is_empty() {
         return 0;
     }
 
@@ -15,12 +15,12 @@ fn extrapolate_right(values: &mut [i64]) -> i64 {
 }
 
 fn extrapolate_left(values: &mut [i64]) -> i64 {
-    if values.len() == 0 {
+    if values.is_empty() {
         return 0;
     }
 
     for i in (1..values.len()).rev() {
-        values[i] = values[i] - values[i - 1];
+        values[i] -= values[i - 1];
     }
 
     let d = extrapolate_left(&mut values[1..]);
@@ -31,34 +31,36 @@ pub struct Day09;
 impl Solution for Day09 {
     type Input<'a> = Vec<Vec<i64>>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum: i64 = input
             .iter()
             .map(|line| extrapolate_right(&mut line.clone()))
             .sum();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum: i64 = input
             .iter()
             .map(|line| extrapolate_left(&mut line.clone()))
             .sum();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
 mod parsing {
-    pub(super) fn parse(input: &str) -> Vec<Vec<i64>> {
+    use aoc_core::{number, AocError};
+
+    pub(super) fn parse(input: &str) -> Result<Vec<Vec<i64>>, AocError> {
         input
             .lines()
             .map(|line| {
                 line.split_ascii_whitespace()
-                    .map(|number| number.parse().unwrap())
+                    .map(|x| number(input, x))
                     .collect()
             })
             .collect()
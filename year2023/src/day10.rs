@@ -1,7 +1,6 @@
-use std::f64::consts::PI;
-
 use super::*;
-use crate::utils::Grid;
+use crate::utils::region::{Connectivity, Region};
+use crate::utils::{Grid, Pos};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Segment {
@@ -33,13 +32,16 @@ pub struct Day10;
 impl Solution for Day10 {
     type Input<'a> = ((isize, isize), Grid<Segment>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let mut distances: Grid<_> =
-            vec![vec![u32::MAX; input.1.width() as usize]; input.1.height() as usize].into();
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let mut distances = Grid::new(
+            input.1.width() as usize,
+            input.1.height() as usize,
+            |_, _| u32::MAX,
+        );
 
         fn walk(segments: &Grid<Segment>, distances: &mut Grid<u32>, x: isize, y: isize, d: u32) {
             if distances[(x, y)] < d {
@@ -72,17 +74,20 @@ impl Solution for Day10 {
             .max()
             .unwrap();
 
-        format!("{}", max)
+        Ok(max.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let mut visited: Grid<_> =
-            vec![vec![false; input.1.width() as usize]; input.1.height() as usize].into();
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let mut visited = Grid::new(
+            input.1.width() as usize,
+            input.1.height() as usize,
+            |_, _| false,
+        );
 
         fn walk_main_loop(
             segments: &Grid<Segment>,
             visited: &mut Grid<bool>,
-            main_loop: &mut Vec<(isize, isize)>,
+            main_loop: &mut Vec<Pos>,
             x: isize,
             y: isize,
         ) {
@@ -91,7 +96,7 @@ impl Solution for Day10 {
             }
             let s = segments[(x, y)];
             visited[(x, y)] = true;
-            main_loop.push((x, y));
+            main_loop.push(Pos::new(x, y));
 
             for offset in s.open_ends().unwrap() {
                 walk_main_loop(segments, visited, main_loop, x + offset.0, y + offset.1);
@@ -100,7 +105,7 @@ impl Solution for Day10 {
 
         let mut main_loop = Vec::new();
         visited[input.0] = true;
-        main_loop.push(input.0);
+        main_loop.push(input.0.into());
         for ((x, y), s) in input.1.neighbors4(input.0 .0, input.0 .1) {
             if let Some(oe) = s.open_ends() {
                 if oe
@@ -112,99 +117,15 @@ impl Solution for Day10 {
             }
         }
 
-        let mut components: Grid<_> =
-            vec![vec![u32::MAX; input.1.width() as usize]; input.1.height() as usize].into();
-
-        let mut component_counter = 0;
-
-        for y in 0..components.height() {
-            for x in 0..components.width() {
-                if !visited[(x, y)] {
-                    let c = components.neighbors8(x, y).map(|(_, c)| *c).min().unwrap();
-                    if c == u32::MAX {
-                        components[(x, y)] = component_counter;
-                        component_counter += 1;
-                    } else {
-                        components[(x, y)] = c;
-                    }
-                }
-            }
-        }
-
-        let mut equiv_components = petgraph::unionfind::UnionFind::new(component_counter as usize);
-        for ((x, y), &c) in &components {
-            for (_, &oc) in components.neighbors8(x, y) {
-                if c != u32::MAX && oc != u32::MAX {
-                    equiv_components.union(c, oc);
-                }
-            }
-        }
-
-        fn winding_number(main_loop: &Vec<(isize, isize)>, x: isize, y: isize) -> i32 {
-            use itertools::Itertools;
-
-            let angle_sum = main_loop
-                .iter()
-                .cycle()
-                .tuple_windows()
-                .take(main_loop.len())
-                .map(|((a0, a1), (b0, b1))| {
-                    let a0 = (a0 - x) as f64;
-                    let a1 = (a1 - y) as f64;
-                    let b0 = (b0 - x) as f64;
-                    let b1 = (b1 - y) as f64;
-
-                    let al = (a0 * a0 + a1 * a1).sqrt();
-                    let bl = (b0 * b0 + b1 * b1).sqrt();
-
-                    let (a0, a1) = (-a1 / al, a0 / al);
-                    let (b0, b1) = (b0 / bl, b1 / bl);
-
-                    let d = a0 * b0 + a1 * b1;
-
-                    d.acos() - PI / 2.0
-                })
-                .sum::<f64>();
-
-            if angle_sum > 0.0 {
-                ((angle_sum / (PI * 2.0)) + 0.5) as i32
-            } else if angle_sum < 0.0 {
-                ((angle_sum / (PI * 2.0)) - 0.5) as i32
-            } else {
-                0
-            }
-        }
-
-        let mut winding_numbers = vec![None; component_counter as usize];
-        let mut nodes_in_component = vec![0; component_counter as usize];
-
-        for y in 0..components.height() {
-            for x in 0..components.width() {
-                if components[(x, y)] == u32::MAX {
-                    continue;
-                }
-                let id = equiv_components.find(components[(x, y)]) as usize;
-                nodes_in_component[id] += 1;
-                if winding_numbers[id].is_none() {
-                    winding_numbers[id] = Some(winding_number(&main_loop, x, y));
-                }
-            }
-        }
-
-        let inside: usize = winding_numbers
+        let inside: usize = visited
+            .components(Connectivity::Eight, |&on_loop| !on_loop)
+            .regions()
             .iter()
-            .zip(nodes_in_component.iter())
-            .filter_map(|(&wn, &c)| {
-                let wn = wn?;
-                if wn != 0 {
-                    Some(c)
-                } else {
-                    None
-                }
-            })
+            .filter(|region| region.enclosed_by(&main_loop))
+            .map(Region::area)
             .sum();
 
-        format!("{}", inside)
+        Ok(inside.into())
     }
 }
 
@@ -212,23 +133,27 @@ mod parsing {
     use super::Segment;
     use crate::utils::Grid;
 
-    pub(super) fn parse(input: &str) -> ((isize, isize), Grid<Segment>) {
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<((isize, isize), Grid<Segment>), AocError> {
         use Segment::*;
-        let grid = Grid::parse(input, |c| match c {
-            '|' => NS,
-            '-' => WE,
-            'L' => NE,
-            'J' => NW,
-            '7' => SW,
-            'F' => SE,
-            '.' => Empty,
-            'S' => Start,
-            _ => unimplemented!(),
-        });
+        let grid = Grid::try_parse(input, |c| match c {
+            '|' => Some(NS),
+            '-' => Some(WE),
+            'L' => Some(NE),
+            'J' => Some(NW),
+            '7' => Some(SW),
+            'F' => Some(SE),
+            '.' => Some(Empty),
+            'S' => Some(Start),
+            _ => None,
+        })?;
 
-        let ((sx, sy), _) = grid.into_iter().find(|((_, _), &s)| s == Start).unwrap();
+        let Some(((sx, sy), _)) = grid.into_iter().find(|((_, _), &s)| s == Start) else {
+            return Err(AocError::parse("the start tile `S` is missing"));
+        };
 
-        ((sx, sy), grid)
+        Ok(((sx, sy), grid))
     }
 }
 
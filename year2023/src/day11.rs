@@ -41,27 +41,29 @@ pub struct Day11;
 impl Solution for Day11 {
     type Input<'a> = Grid<bool>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        format!("{}", measure_expanded(input, 2))
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Ok(measure_expanded(input, 2).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        format!("{}", measure_expanded(input, 1000000))
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Ok(measure_expanded(input, 1000000).into())
     }
 }
 
 mod parsing {
     use crate::utils::Grid;
 
-    pub(super) fn parse(input: &str) -> Grid<bool> {
-        Grid::parse(input, |c| match c {
-            '.' => false,
-            '#' => true,
-            _ => unimplemented!(),
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<Grid<bool>, AocError> {
+        Grid::try_parse(input, |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
         })
     }
 }
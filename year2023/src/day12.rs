@@ -15,11 +15,11 @@ fn calc_possibilities_internal(
     amount: &mut [u32],
     cache: &mut HashMap<(u32, u32, u32, u32), u64>,
 ) -> u64 {
-    if status.len() == 0 {
-        return (amount.len() == 0 || amount.len() == 1 && amount[0] == 0) as u64;
+    if status.is_empty() {
+        return (amount.is_empty() || amount.len() == 1 && amount[0] == 0) as u64;
     }
 
-    if amount.len() == 0 {
+    if amount.is_empty() {
         return status
             .iter()
             .all(|x| x.iter().all(|x| *x == Status::Unknown)) as u64;
@@ -81,7 +81,7 @@ fn calc_possibilities_internal(
 fn calc_possibilities(status: &[Status], amount: &mut [u32]) -> u64 {
     let mut grouped_status = Vec::new();
     let mut remaining_status = status;
-    while remaining_status.len() > 0 {
+    while !remaining_status.is_empty() {
         let Some(p) = remaining_status
             .iter()
             .position(|x| *x == Status::Operational)
@@ -107,33 +107,33 @@ pub struct Day12;
 impl Solution for Day12 {
     type Input<'a> = Vec<(Vec<Status>, Vec<u32>)>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum = input
             .iter()
-            .map(|(status, amount)| calc_possibilities(&status, &mut amount.clone()))
+            .map(|(status, amount)| calc_possibilities(status, &mut amount.clone()))
             .sum::<u64>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum = input
             .par_iter()
             .map(|(status, amount)| {
                 let mut unfolded_status = Vec::new();
-                unfolded_status.extend_from_slice(&status);
+                unfolded_status.extend_from_slice(status);
                 unfolded_status.push(Status::Unknown);
-                unfolded_status.extend_from_slice(&status);
+                unfolded_status.extend_from_slice(status);
                 unfolded_status.push(Status::Unknown);
-                unfolded_status.extend_from_slice(&status);
+                unfolded_status.extend_from_slice(status);
                 unfolded_status.push(Status::Unknown);
-                unfolded_status.extend_from_slice(&status);
+                unfolded_status.extend_from_slice(status);
                 unfolded_status.push(Status::Unknown);
-                unfolded_status.extend_from_slice(&status);
+                unfolded_status.extend_from_slice(status);
                 let mut amount = amount
                     .iter()
                     .copied()
@@ -144,7 +144,7 @@ impl Solution for Day12 {
             })
             .sum::<u64>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
@@ -176,7 +176,9 @@ mod parsing {
         separated_pair(status, space1, amount)(input)
     }
 
-    pub(super) fn parse(input: &str) -> IResult<&str, Vec<(Vec<Status>, Vec<u32>)>> {
+    type Row = (Vec<Status>, Vec<u32>);
+
+    pub(super) fn parse(input: &str) -> IResult<&str, Vec<Row>> {
         separated_list1(line_ending, line)(input)
     }
 }
@@ -53,49 +53,54 @@ pub struct Day13;
 impl Solution for Day13 {
     type Input<'a> = Vec<Grid<Node>>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum = input
             .iter()
             .map(|mirror| reflection_score(mirror, 0))
             .sum::<u64>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum = input
             .iter()
             .map(|mirror| reflection_score(mirror, 1))
             .sum::<u64>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
 mod parsing {
     use super::Node;
     use crate::utils::Grid;
+    use itertools::Itertools;
     use nom::{
         character::complete::{char, line_ending},
+        combinator::verify,
         multi::{many1, separated_list1},
         sequence::tuple,
         *,
     };
 
     fn mirror(input: &str) -> IResult<&str, Grid<Node>> {
-        separated_list1(
-            line_ending,
-            many1(
-                char('#')
-                    .map(|_| Node::Rock)
-                    .or(char('.').map(|_| Node::Ash)),
+        verify(
+            separated_list1(
+                line_ending,
+                many1(
+                    char('#')
+                        .map(|_| Node::Rock)
+                        .or(char('.').map(|_| Node::Ash)),
+                ),
             ),
+            |rows: &Vec<Vec<Node>>| rows.iter().map(Vec::len).all_equal(),
         )
-        .map(|m| m.into())
+        .map(|rows| Grid::from_vec(rows[0].len(), rows.concat()))
         .parse(input)
     }
 
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use super::*;
 use crate::utils::Grid;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Tile {
     Empty,
     Round,
@@ -27,13 +27,6 @@ impl std::fmt::Display for Tile {
     }
 }
 
-enum Direction {
-    N,
-    E,
-    S,
-    W,
-}
-
 fn calc_load(tiles: &Grid<Tile>) -> u64 {
     fn calc_load_internal(tiles: &Grid<Tile>, row: isize, col: isize, load: u64) -> u64 {
         if row >= tiles.height() {
@@ -53,79 +46,38 @@ fn calc_load(tiles: &Grid<Tile>) -> u64 {
         .sum()
 }
 
-fn cube_rocks(tiles: &Grid<Tile>) -> Vec<(isize, isize)> {
-    tiles
-        .into_iter()
-        .filter(|(_, &x)| x == Tile::Cube)
-        .map(|((x, y), _)| (x, y))
-        .chain((0..tiles.height()).map(|y| (-1, y)))
-        .chain((0..tiles.height()).map(|y| (tiles.width(), y)))
-        .chain((0..tiles.width()).map(|x| (x, -1)))
-        .chain((0..tiles.width()).map(|x| (x, tiles.height())))
-        .collect()
-}
-
-fn tilt(
-    tiles: &mut Grid<Tile>,
-    cube_rocks: &Vec<(isize, isize)>,
-    direction: &Direction,
-) -> Vec<u8> {
-    let mut load = vec![0u8; cube_rocks.len()];
-
-    let d = match direction {
-        &Direction::N => (0, 1),
-        &Direction::S => (0, -1),
-        &Direction::E => (-1, 0),
-        &Direction::W => (1, 0),
-    };
-
-    for (i, &(x, y)) in cube_rocks.iter().enumerate() {
-        let mut current = (x + d.0, y + d.1);
-        loop {
-            let Some(&tile) = tiles.get(current.0, current.1) else {
-                break;
-            };
-            match tile {
-                Tile::Cube => {
-                    break;
-                }
+/// Rolls all round rocks as far north as they go.
+fn tilt_north(tiles: &mut Grid<Tile>) {
+    for x in 0..tiles.width() {
+        let mut free = 0;
+        for y in 0..tiles.height() {
+            match tiles[(x, y)] {
+                Tile::Cube => free = y + 1,
                 Tile::Round => {
-                    load[i] += 1;
-                    tiles[current] = Tile::Empty;
+                    tiles[(x, y)] = Tile::Empty;
+                    tiles[(x, free)] = Tile::Round;
+                    free += 1;
                 }
-                _ => {}
+                Tile::Empty => {}
             }
-            current.0 += d.0;
-            current.1 += d.1;
-        }
-        current = (x + d.0, y + d.1);
-        for _ in 0..load[i] {
-            tiles[current] = Tile::Round;
-            current.0 += d.0;
-            current.1 += d.1;
         }
     }
-
-    load
 }
 
+/// Tilts north, west, south and east by tilting north and rotating the platform four times.
 fn spin_cycle(tiles: &mut Grid<Tile>, cycles: u64) {
     let mut cache = HashMap::new();
 
-    let directions = [Direction::N, Direction::W, Direction::S, Direction::E];
-
-    let cubes = cube_rocks(tiles);
-
     let mut i = 0u64;
     loop {
         i += 1;
 
-        let mut k = Vec::new();
-        for d in &directions {
-            k = tilt(tiles, &cubes, d);
+        for _ in 0..4 {
+            tilt_north(tiles);
+            *tiles = tiles.rotate_cw();
         }
 
-        let &mut j = cache.entry(k).or_insert(i);
+        let &mut j = cache.entry(tiles.as_slice().to_vec()).or_insert(i);
         if i != j {
             let diff = i - j;
             let rem = cycles - i;
@@ -142,16 +94,16 @@ pub struct Day14;
 impl Solution for Day14 {
     type Input<'a> = Grid<Tile>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let load = calc_load(input);
-        format!("{}", load)
+        Ok(load.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut input = input.clone();
         spin_cycle(&mut input, 1000000000);
 
@@ -166,7 +118,7 @@ impl Solution for Day14 {
             })
             .sum::<u64>();
 
-        format!("{}", load)
+        Ok(load.into())
     }
 }
 
@@ -174,12 +126,14 @@ mod parsing {
     use super::Tile;
     use crate::utils::Grid;
 
-    pub(super) fn parse(input: &str) -> Grid<Tile> {
-        Grid::parse(input, |c| match c {
-            'O' => Tile::Round,
-            '#' => Tile::Cube,
-            '.' => Tile::Empty,
-            _ => unimplemented!(),
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<Grid<Tile>, AocError> {
+        Grid::try_parse(input, |c| match c {
+            'O' => Some(Tile::Round),
+            '#' => Some(Tile::Cube),
+            '.' => Some(Tile::Empty),
+            _ => None,
         })
     }
 }
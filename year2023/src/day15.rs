@@ -18,18 +18,19 @@ pub struct Day15;
 impl Solution for Day15 {
     type Input<'a> = Vec<&'a str>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        content.split(',').collect()
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Ok(content.split(',').collect())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let h: u64 = input.iter().map(|instr| hash(instr) as u64).sum();
-        format!("{}", h)
+        Ok(h.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut boxes: [_; 256] = core::array::from_fn(|_| VecDeque::new());
-        for (label, instr) in input.iter().map(|s| parsing::parse(s).unwrap().1) {
+        for step in input {
+            let (label, instr) = finish(step, parsing::parse(step))?;
             let h = hash(label);
             match instr {
                 Instruction::Set(focal_length) => {
@@ -59,7 +60,7 @@ impl Solution for Day15 {
             })
             .sum();
 
-        format!("{}", res)
+        Ok(res.into())
     }
 }
 
@@ -71,7 +72,7 @@ mod parsing {
         *,
     };
 
-    pub(super) fn parse<'a>(input: &'a str) -> IResult<&'a str, (&'a str, Instruction)> {
+    pub(super) fn parse(input: &str) -> IResult<&str, (&str, Instruction)> {
         tuple((
             alpha1,
             char('-')
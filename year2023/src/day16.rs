@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use super::*;
-use crate::utils::Grid;
+use crate::utils::{Dir4, Grid, Pos};
 
 #[derive(Clone, Copy, Debug)]
 pub enum Tile {
@@ -12,65 +12,40 @@ pub enum Tile {
     MirrorNE,
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    N,
-    E,
-    S,
-    W,
-}
-
-impl Direction {
-    fn offset(&self) -> (isize, isize) {
-        match *self {
-            Direction::N => (0, -1),
-            Direction::S => (0, 1),
-            Direction::W => (-1, 0),
-            Direction::E => (1, 0),
-        }
-    }
-}
-
 impl Tile {
-    fn bounce(&self, direction: Direction) -> [Option<Direction>; 2] {
+    fn bounce(&self, direction: Dir4) -> [Option<Dir4>; 2] {
         match *self {
             Tile::Empty => [Some(direction), None],
             Tile::SplitterV => match direction {
-                Direction::E | Direction::W => [Some(Direction::N), Some(Direction::S)],
-                Direction::N | Direction::S => [Some(direction), None],
+                Dir4::E | Dir4::W => [Some(Dir4::N), Some(Dir4::S)],
+                Dir4::N | Dir4::S => [Some(direction), None],
             },
             Tile::SplitterH => match direction {
-                Direction::N | Direction::S => [Some(Direction::E), Some(Direction::W)],
-                Direction::E | Direction::W => [Some(direction), None],
+                Dir4::N | Dir4::S => [Some(Dir4::E), Some(Dir4::W)],
+                Dir4::E | Dir4::W => [Some(direction), None],
             },
             Tile::MirrorNW => match direction {
-                Direction::N => [Some(Direction::E), None],
-                Direction::W => [Some(Direction::S), None],
-                Direction::S => [Some(Direction::W), None],
-                Direction::E => [Some(Direction::N), None],
+                Dir4::N => [Some(Dir4::E), None],
+                Dir4::W => [Some(Dir4::S), None],
+                Dir4::S => [Some(Dir4::W), None],
+                Dir4::E => [Some(Dir4::N), None],
             },
             Tile::MirrorNE => match direction {
-                Direction::N => [Some(Direction::W), None],
-                Direction::E => [Some(Direction::S), None],
-                Direction::S => [Some(Direction::E), None],
-                Direction::W => [Some(Direction::N), None],
+                Dir4::N => [Some(Dir4::W), None],
+                Dir4::E => [Some(Dir4::S), None],
+                Dir4::S => [Some(Dir4::E), None],
+                Dir4::W => [Some(Dir4::N), None],
             },
         }
     }
 }
 
-fn trace_path(
-    input: &Grid<Tile>,
-    position: (isize, isize),
-    direction: Direction,
-) -> [Grid<bool>; 4] {
-    let rays = vec![vec![false; input.width() as usize]; input.height() as usize];
-    let mut rays: [Grid<bool>; 4] = [
-        rays.clone().into(),
-        rays.clone().into(),
-        rays.clone().into(),
-        rays.clone().into(),
-    ];
+fn trace_path(input: &Grid<Tile>, position: Pos, direction: Dir4) -> [Grid<bool>; 4] {
+    let mut rays: [Grid<bool>; 4] = core::array::from_fn(|_| {
+        Grid::new(input.width() as usize, input.height() as usize, |_, _| {
+            false
+        })
+    });
 
     rays[direction as usize][position] = true;
     let mut todo = VecDeque::new();
@@ -78,10 +53,9 @@ fn trace_path(
 
     while let Some((dir, pos)) = todo.pop_front() {
         for new_dir in input[pos].bounce(dir).into_iter().flatten() {
-            let offset = new_dir.offset();
-            let new_pos = (pos.0 + offset.0, pos.1 + offset.1);
+            let new_pos = pos + new_dir;
 
-            if rays[new_dir as usize].get(new_pos.0, new_pos.1) == Some(&false) {
+            if rays[new_dir as usize].get_pos(new_pos) == Some(&false) {
                 rays[new_dir as usize][new_pos] = true;
                 todo.push_back((new_dir, new_pos));
             }
@@ -91,50 +65,30 @@ fn trace_path(
     rays
 }
 
-#[allow(dead_code)]
-fn print(input: &Grid<Tile>, rays: &[Grid<bool>; 4]) {
-    for y in 0..input.height() {
-        for x in 0..input.width() {
-            if rays[0][(x, y)] {
-                print!("^");
-            } else if rays[1][(x, y)] {
-                print!(">");
-            } else if rays[2][(x, y)] {
-                print!("v");
-            } else if rays[3][(x, y)] {
-                print!("<");
-            } else {
-                print!(".");
-            }
-        }
-        println!("");
-    }
-}
-
 pub struct Day16;
 impl Solution for Day16 {
     type Input<'a> = Grid<Tile>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let rays = trace_path(input, (0, 0), Direction::E);
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let rays = trace_path(input, Pos::ORIGIN, Dir4::E);
 
         let count = input
             .into_iter()
             .filter(|&(p, _)| rays[0][p] || rays[1][p] || rays[2][p] || rays[3][p])
             .count();
 
-        format!("{}", count)
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let top = (0..input.width()).map(|x| (Direction::S, (x, 0)));
-        let bottom = (0..input.width()).map(|x| (Direction::N, (x, input.height() - 1)));
-        let left = (0..input.height()).map(|y| (Direction::E, (0, y)));
-        let right = (0..input.height()).map(|y| (Direction::W, (input.width() - 1, y)));
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let top = (0..input.width()).map(|x| (Dir4::S, Pos::new(x, 0)));
+        let bottom = (0..input.width()).map(|x| (Dir4::N, Pos::new(x, input.height() - 1)));
+        let left = (0..input.height()).map(|y| (Dir4::E, Pos::new(0, y)));
+        let right = (0..input.height()).map(|y| (Dir4::W, Pos::new(input.width() - 1, y)));
 
         let max_count = top
             .chain(bottom)
@@ -151,7 +105,7 @@ impl Solution for Day16 {
             .max()
             .unwrap();
 
-        format!("{}", max_count)
+        Ok(max_count.into())
     }
 }
 
@@ -160,14 +114,16 @@ mod parsing {
 
     use super::Tile;
 
-    pub(super) fn parse(input: &str) -> Grid<Tile> {
-        Grid::parse(input, |c| match c {
-            '.' => Tile::Empty,
-            '|' => Tile::SplitterV,
-            '-' => Tile::SplitterH,
-            '/' => Tile::MirrorNW,
-            '\\' => Tile::MirrorNE,
-            _ => unreachable!(),
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<Grid<Tile>, AocError> {
+        Grid::try_parse(input, |c| match c {
+            '.' => Some(Tile::Empty),
+            '|' => Some(Tile::SplitterV),
+            '-' => Some(Tile::SplitterH),
+            '/' => Some(Tile::MirrorNW),
+            '\\' => Some(Tile::MirrorNE),
+            _ => None,
         })
     }
 }
@@ -1,37 +1,20 @@
 use super::*;
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    R,
-    L,
-    D,
-    U,
-}
-
-impl Direction {
-    fn as_vec(&self) -> (i64, i64) {
-        match self {
-            Direction::R => (1, 0),
-            Direction::L => (-1, 0),
-            Direction::U => (0, -1),
-            Direction::D => (0, 1),
-        }
-    }
-}
+use crate::utils::Dir4;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Instruction {
-    direction: Direction,
+    direction: Dir4,
     steps: u32,
     color: u32,
 }
 
-fn calc_area(instructions: impl IntoIterator<Item = (Direction, i64)>) -> i64 {
+fn calc_area(instructions: impl IntoIterator<Item = (Dir4, i64)>) -> i64 {
     let mut position = (0, 0);
     let (int, len) = instructions
         .into_iter()
         .map(|(d, s)| {
-            let d = d.as_vec();
+            let d = d.offset();
+            let d = (d.x as i64, d.y as i64);
             position = (position.0 + s * d.0, position.1 + s * d.1);
 
             (s * (position.1 * d.0 - position.0 * d.1), s)
@@ -45,57 +28,48 @@ pub struct Day18;
 impl Solution for Day18 {
     type Input<'a> = Vec<Instruction>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let area = calc_area(input.iter().map(|i| (i.direction, i.steps as i64)));
 
-        format!("{}", area)
+        Ok(area.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let area = calc_area(input.iter().map(|i| {
-            let direction = match i.color % 4 {
-                0 => Direction::R,
-                1 => Direction::D,
-                2 => Direction::L,
-                3 => Direction::U,
-                _ => unreachable!(),
-            };
+            let direction = [Dir4::E, Dir4::S, Dir4::W, Dir4::N][i.color as usize % 4];
             let steps = (i.color >> 4) as i64;
             (direction, steps)
         }));
 
-        format!("{}", area)
+        Ok(area.into())
     }
 }
 
 mod parsing {
     use nom::{
         bytes::complete::tag,
-        character::complete::{char, hex_digit1, line_ending, space1, u32},
+        character::complete::{hex_digit1, line_ending, one_of, space1, u32},
+        combinator::map_res,
         multi::separated_list1,
         sequence::{delimited, tuple},
         *,
     };
 
-    use super::{Direction, Instruction};
+    use super::{Dir4, Instruction};
 
-    fn direction(input: &str) -> IResult<&str, Direction> {
-        char('R')
-            .map(|_| Direction::R)
-            .or(char('L').map(|_| Direction::L))
-            .or(char('U').map(|_| Direction::U))
-            .or(char('D').map(|_| Direction::D))
-            .parse(input)
+    fn direction(input: &str) -> IResult<&str, Dir4> {
+        map_res(one_of("RLUD"), Dir4::try_from).parse(input)
     }
 
     fn color(input: &str) -> IResult<&str, u32> {
-        delimited(tag("(#"), hex_digit1, tag(")"))
-            .map(|h| u32::from_str_radix(h, 16).unwrap())
-            .parse(input)
+        map_res(delimited(tag("(#"), hex_digit1, tag(")")), |h| {
+            u32::from_str_radix(h, 16)
+        })
+        .parse(input)
     }
 
     fn instruction(input: &str) -> IResult<&str, Instruction> {
@@ -103,15 +103,15 @@ pub struct Day19;
 impl Solution for Day19 {
     type Input<'a> = (HashMap<&'a str, Workflow<'a>>, Vec<Part>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        let (workflows, parts) = parsing::parse(content).unwrap().1;
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let (workflows, parts) = finish(content, parsing::parse(content))?;
 
         let workflows: HashMap<_, _> = workflows.into_iter().collect();
 
-        (workflows, parts)
+        Ok((workflows, parts))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let res = input
             .1
             .iter()
@@ -126,10 +126,10 @@ impl Solution for Day19 {
             .map(|part| part.iter().sum::<u32>() as u64)
             .sum::<u64>();
 
-        format!("{}", res)
+        Ok(res.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut partition = vec![([1..=4000, 1..=4000, 1..=4000, 1..=4000], "in")];
 
         let mut accepted = 0u64;
@@ -137,16 +137,14 @@ impl Solution for Day19 {
         while let Some((i, w)) = partition.pop() {
             for (i, w) in input.0[w].apply_to_intervals(i) {
                 if w == "A" {
-                    accepted += i.iter().map(|a| a.clone().count() as u64).product::<u64>() as u64;
+                    accepted += i.iter().map(|a| a.clone().count() as u64).product::<u64>();
                 } else if w != "R" {
                     partition.push((i, w))
                 }
             }
-
-            println!("{:#?}", partition);
         }
 
-        format!("{}", accepted)
+        Ok(accepted.into())
     }
 }
 
@@ -197,7 +195,7 @@ mod parsing {
         Ok((input, res))
     }
 
-    fn workflow(input: &str) -> IResult<&str, (&str, Workflow)> {
+    fn workflow(input: &str) -> IResult<&str, (&str, Workflow<'_>)> {
         fn test(input: &str) -> IResult<&str, (Condition, &str)> {
             tuple((
                 alt((
@@ -238,7 +236,9 @@ mod parsing {
         ))(input)
     }
 
-    pub(super) fn parse(input: &str) -> IResult<&str, (Vec<(&str, Workflow)>, Vec<Part>)> {
+    type System<'a> = (Vec<(&'a str, Workflow<'a>)>, Vec<Part>);
+
+    pub(super) fn parse(input: &str) -> IResult<&str, System<'_>> {
         separated_pair(
             separated_list0(line_ending, workflow),
             tuple((line_ending, line_ending)),
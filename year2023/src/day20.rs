@@ -38,9 +38,9 @@ fn perform_step(
     let mut low_count = 1;
     let mut high_count = 0;
     let mut detailed_low_count = SmallVec::new();
-    detailed_low_count.extend(std::iter::repeat(0).take(system.modules.len()));
+    detailed_low_count.extend(std::iter::repeat_n(0, system.modules.len()));
     let mut detailed_high_count = SmallVec::new();
-    detailed_high_count.extend(std::iter::repeat(0).take(system.modules.len()));
+    detailed_high_count.extend(std::iter::repeat_n(0, system.modules.len()));
 
     while let Some((idx, high)) = queue.pop_front() {
         low_count += !high as u64;
@@ -92,11 +92,11 @@ pub struct Day20;
 impl Solution for Day20 {
     type Input<'a> = System<'a>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut state = 0;
 
         let mut low_count = 0;
@@ -108,10 +108,10 @@ impl Solution for Day20 {
             high_count += high;
         }
 
-        format!("{}", low_count * high_count)
+        Ok((low_count * high_count).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let s0 = input.modules[input.rx as usize].in_list[0];
         let res = input.modules[s0 as usize]
             .in_list
@@ -126,7 +126,7 @@ impl Solution for Day20 {
             })
             .fold(1u64, |acc, x| acc.lcm(&x));
 
-        format!("{}", res)
+        Ok(res.into())
     }
 }
 
@@ -134,6 +134,7 @@ mod parsing {
     use std::collections::HashMap;
 
     use super::ModuleType;
+    use aoc_core::{finish, AocError};
     use nom::{
         branch::alt,
         bytes::complete::tag,
@@ -144,7 +145,7 @@ mod parsing {
     };
     use smallvec::SmallVec;
 
-    fn module_name(input: &str) -> IResult<&str, ModuleType> {
+    fn module_name(input: &str) -> IResult<&str, ModuleType<'_>> {
         alt((
             tuple((char('%'), alpha1)).map(|(_, name)| ModuleType::FlipFlop(name)),
             tuple((char('&'), alpha1)).map(|(_, name)| ModuleType::Conjunction(name)),
@@ -156,7 +157,7 @@ mod parsing {
         separated_list0(tuple((space0, char(','), space0)), alpha1)(input)
     }
 
-    fn module(input: &str) -> IResult<&str, (ModuleType, Vec<&str>)> {
+    fn module(input: &str) -> IResult<&str, (ModuleType<'_>, Vec<&str>)> {
         separated_pair(
             module_name,
             tuple((space0, tag("->"), space0)),
@@ -164,12 +165,12 @@ mod parsing {
         )(input)
     }
 
-    fn module_list(input: &str) -> IResult<&str, Vec<(ModuleType, Vec<&str>)>> {
+    fn module_list(input: &str) -> IResult<&str, Vec<(ModuleType<'_>, Vec<&str>)>> {
         separated_list0(line_ending, module)(input)
     }
 
-    pub(super) fn parse(input: &str) -> super::System {
-        let modules = module_list(input).unwrap().1;
+    pub(super) fn parse(input: &str) -> Result<super::System<'_>, AocError> {
+        let modules = finish(input, module_list(input))?;
 
         let mut broadcast: SmallVec<[u8; 64]> = SmallVec::new();
         let mut map = HashMap::new();
@@ -221,11 +222,15 @@ mod parsing {
             }
         }
 
-        super::System {
+        let Some(&rx) = map.get("rx") else {
+            return Err(AocError::parse("no module is connected to `rx`"));
+        };
+
+        Ok(super::System {
             modules: listed_modules,
             broadcast,
-            rx: map["rx"],
-        }
+            rx,
+        })
     }
 }
 
@@ -240,4 +245,3 @@ gen_test!(
 ",
     "11687500"
 );
-// gen_test!(b, Day20, r"", "");
@@ -12,8 +12,11 @@ pub enum Tile {
 }
 
 fn perform_step(garden: &Grid<Tile>, positions: &Grid<bool>) -> Grid<bool> {
-    let mut out: Grid<bool> =
-        vec![vec![false; positions.width() as usize]; positions.height() as usize].into();
+    let mut out = Grid::new(
+        positions.width() as usize,
+        positions.height() as usize,
+        |_, _| false,
+    );
 
     for position in positions
         .into_iter()
@@ -30,8 +33,9 @@ fn perform_step(garden: &Grid<Tile>, positions: &Grid<bool>) -> Grid<bool> {
 }
 
 fn sim_with_start_pos(garden: &Grid<Tile>, start_pos: (isize, isize)) -> Vec<usize> {
-    let mut pos: Grid<bool> =
-        vec![vec![false; garden.width() as usize]; garden.height() as usize].into();
+    let mut pos = Grid::new(garden.width() as usize, garden.height() as usize, |_, _| {
+        false
+    });
     pos.set_wrapping(true);
     pos[start_pos] = true;
     pos.set_wrapping(false);
@@ -64,21 +68,44 @@ fn get_c_at_time(cs: &[usize], t: usize) -> usize {
     cs[idx]
 }
 
+/// The number of steps the elf takes in each part
+#[derive(Clone, Copy, Debug)]
+pub struct Steps {
+    a: usize,
+    b: isize,
+}
+
+impl Steps {
+    fn new(params: &Params) -> Result<Self, AocError> {
+        params.check(&["steps_a", "steps_b"])?;
+        Ok(Self {
+            a: params.get("steps_a", 64)?,
+            b: params.get("steps_b", 26501365)?,
+        })
+    }
+}
+
 pub struct Day21;
 impl Solution for Day21 {
-    type Input<'a> = (Grid<Tile>, (isize, isize));
+    type Input<'a> = (Grid<Tile>, (isize, isize), Steps);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content)
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Self::parse_with(content, &Params::new())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+        let steps = Steps::new(params)?;
+        let (garden, start) = parsing::parse(content)?;
+        Ok((garden, start, steps))
+    }
+
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let counts = sim_with_start_pos(&input.0, input.1);
-        format!("{}", get_c_at_time(&counts, 64))
+        Ok(get_c_at_time(&counts, input.2.a).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        const STEPS: isize = 26501365;
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let steps = input.2.b;
 
         let x = input.1 .0;
         let y = input.1 .1;
@@ -95,8 +122,8 @@ impl Solution for Day21 {
         let se = sim_with_start_pos(&input.0, (-1, -1));
         let sw = sim_with_start_pos(&input.0, (0, -1));
 
-        let ext_x = STEPS / input.0.width() + 1;
-        let ext_y = STEPS / input.0.height() + 1;
+        let ext_x = steps / input.0.width() + 1;
+        let ext_y = steps / input.0.height() + 1;
 
         let count: usize = ((-ext_x)..=ext_x)
             .into_par_iter()
@@ -123,11 +150,11 @@ impl Solution for Day21 {
                         }
                     }
 
-                    if offset > STEPS {
+                    if offset > steps {
                         continue;
                     }
 
-                    let t = (STEPS - offset) as usize;
+                    let t = (steps - offset) as usize;
 
                     count += match (x.cmp(&0), y.cmp(&0)) {
                         (std::cmp::Ordering::Less, std::cmp::Ordering::Less) => {
@@ -163,7 +190,7 @@ impl Solution for Day21 {
             })
             .sum();
 
-        format!("{count}")
+        Ok(count.into())
     }
 }
 
@@ -172,27 +199,46 @@ mod parsing {
     use super::Tile;
     use crate::utils::Grid;
 
-    pub(super) fn parse(input: &str) -> (Grid<Tile>, (isize, isize)) {
-        let mut grid = Grid::parse(input, |c| match c {
-            '.' => Tile::GardenPlot,
-            'S' => Tile::Start,
-            '#' => Tile::Rock,
-            _ => unimplemented!(),
-        });
-
-        let start = grid
-            .into_iter()
-            .find_map(|((x, y), t)| {
-                if t == &Tile::Start {
-                    Some((x, y))
-                } else {
-                    None
-                }
-            })
-            .unwrap();
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<(Grid<Tile>, (isize, isize)), AocError> {
+        let mut grid = Grid::try_parse(input, |c| match c {
+            '.' => Some(Tile::GardenPlot),
+            'S' => Some(Tile::Start),
+            '#' => Some(Tile::Rock),
+            _ => None,
+        })?;
+
+        let Some(start) = grid.into_iter().find_map(|((x, y), t)| {
+            if t == &Tile::Start {
+                Some((x, y))
+            } else {
+                None
+            }
+        }) else {
+            return Err(AocError::parse("the start tile `S` is missing"));
+        };
 
         grid[start] = Tile::GardenPlot;
 
-        (grid, start)
+        Ok((grid, start))
     }
 }
+
+gen_test!(
+    a,
+    Day21,
+    r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........",
+    "16",
+    steps_a = 6
+);
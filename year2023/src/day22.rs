@@ -23,22 +23,20 @@ impl Brick {
 
 impl PartialOrd for Brick {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(
-            self.lower
-                .2
-                .cmp(&other.lower.2)
-                .then_with(|| self.lower.0.cmp(&other.lower.0))
-                .then_with(|| self.lower.1.cmp(&other.lower.1))
-                .then_with(|| self.upper.2.cmp(&other.upper.2))
-                .then_with(|| self.upper.0.cmp(&other.upper.0))
-                .then_with(|| self.upper.1.cmp(&other.upper.1)),
-        )
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Brick {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        self.lower
+            .2
+            .cmp(&other.lower.2)
+            .then_with(|| self.lower.0.cmp(&other.lower.0))
+            .then_with(|| self.lower.1.cmp(&other.lower.1))
+            .then_with(|| self.upper.2.cmp(&other.upper.2))
+            .then_with(|| self.upper.0.cmp(&other.upper.0))
+            .then_with(|| self.upper.1.cmp(&other.upper.1))
     }
 }
 
@@ -56,7 +54,7 @@ fn get_tower_area<'a>(bricks: impl IntoIterator<Item = &'a Brick>) -> (i32, i32,
 }
 
 fn simulate_fall(bricks: impl IntoIterator<Item = Brick>) -> Vec<Brick> {
-    let mut bricks = Vec::from_iter(bricks.into_iter());
+    let mut bricks = Vec::from_iter(bricks);
     if bricks.is_empty() {
         return bricks;
     }
@@ -117,9 +115,11 @@ fn evaluate_chain_reaction(
     let mut fallen_bricks = BTreeSet::new();
     fallen_bricks.insert(removed_index);
 
-    for i in (removed_index + 1)..support_structure.len() {
-        let s = &support_structure[i];
-
+    for (i, s) in support_structure
+        .iter()
+        .enumerate()
+        .skip(removed_index + 1)
+    {
         if !s.is_empty() && s.is_subset(&fallen_bricks) {
             fallen_bricks.insert(i);
         }
@@ -133,11 +133,11 @@ pub struct Day22;
 impl Solution for Day22 {
     type Input<'a> = Vec<Brick>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let fallen_bricks = simulate_fall(input.iter().copied());
         let support = build_support_structure(&fallen_bricks);
 
@@ -145,10 +145,10 @@ impl Solution for Day22 {
             .filter(|i| evaluate_chain_reaction(&support, *i).is_empty())
             .count();
 
-        format!("{count}")
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let fallen_bricks = simulate_fall(input.iter().copied());
         let support = build_support_structure(&fallen_bricks);
 
@@ -159,30 +159,36 @@ impl Solution for Day22 {
             })
             .sum::<usize>();
 
-        format!("{sum}")
+        Ok(sum.into())
     }
 }
 
 mod parsing {
     use super::Brick;
+    use aoc_core::{number, AocError};
 
-    pub(super) fn parse(input: &str) -> Vec<Brick> {
+    pub(super) fn parse(input: &str) -> Result<Vec<Brick>, AocError> {
         input
             .lines()
-            .filter_map(|line| {
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
                 let parts: Vec<_> = line.split([',', '~']).collect();
-                let &[x0, y0, z0, x1, y1, z1, ..] = parts.as_slice() else {
-                    return None;
+                let &[x0, y0, z0, x1, y1, z1] = parts.as_slice() else {
+                    return Err(AocError::parse_at(
+                        input,
+                        line,
+                        "expected a brick `x,y,z~x,y,z`",
+                    ));
                 };
 
-                let x0 = x0.parse::<i32>().ok()?;
-                let y0 = y0.parse::<i32>().ok()?;
-                let z0 = z0.parse::<i32>().ok()?;
-                let x1 = x1.parse::<i32>().ok()?;
-                let y1 = y1.parse::<i32>().ok()?;
-                let z1 = z1.parse::<i32>().ok()?;
+                let x0 = number::<i32>(input, x0)?;
+                let y0 = number::<i32>(input, y0)?;
+                let z0 = number::<i32>(input, z0)?;
+                let x1 = number::<i32>(input, x1)?;
+                let y1 = number::<i32>(input, y1)?;
+                let z1 = number::<i32>(input, z1)?;
 
-                Some(Brick {
+                Ok(Brick {
                     lower: (x0.min(x1), y0.min(y1), z0.min(z1)),
                     upper: (x0.max(x1), y0.max(y1), z0.max(z1)),
                 })
@@ -1,32 +1,13 @@
 use std::collections::{HashMap, VecDeque};
 
 use super::*;
-use crate::utils::Grid;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    N,
-    E,
-    S,
-    W,
-}
-
-impl Direction {
-    fn offset(&self) -> (isize, isize) {
-        match self {
-            Direction::N => (0, -1),
-            Direction::E => (1, 0),
-            Direction::S => (0, 1),
-            Direction::W => (-1, 0),
-        }
-    }
-}
+use crate::utils::{Dir4, Grid, Pos};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tile {
     Path,
     Forest,
-    Slope(Direction),
+    Slope(Dir4),
 }
 
 #[derive(Debug, Clone)]
@@ -77,22 +58,23 @@ fn to_graph(grid: &Grid<Tile>) -> HikingGraph {
     fn trace_path(
         grid: &Grid<Tile>,
         visited: &mut Grid<bool>,
-        last: (isize, isize),
-        current: (isize, isize),
+        last: Pos,
+        current: Pos,
         length: u64,
-    ) -> (u64, (isize, isize), bool, bool) {
+    ) -> (u64, Pos, bool, bool) {
         if visited[current] {
             return (length, current, true, true);
         }
 
         let mut next = None;
         let mut is_junction = false;
-        for ((x, y), t) in grid.neighbors4(current.0, current.1) {
-            if t == &Tile::Forest || last == (x, y) {
+        for ((x, y), t) in grid.neighbors4(current.x, current.y) {
+            let pos = Pos::new(x, y);
+            if t == &Tile::Forest || last == pos {
                 continue;
             }
             if next.is_none() {
-                next = Some((x, y));
+                next = Some(pos);
             } else {
                 is_junction = true;
             }
@@ -109,7 +91,7 @@ fn to_graph(grid: &Grid<Tile>) -> HikingGraph {
         let mut res = trace_path(grid, visited, current, next, length + 1);
 
         if let Tile::Slope(dir) = grid[current] {
-            let forced_next = (current.0 + dir.offset().0, current.1 + dir.offset().1);
+            let forced_next = current + dir;
             if next == forced_next {
                 res.3 = false;
             } else if last == forced_next {
@@ -123,8 +105,8 @@ fn to_graph(grid: &Grid<Tile>) -> HikingGraph {
         res
     }
 
-    let start_position = (1isize, 0isize);
-    let end_position = (grid.width() - 2, grid.height() - 1);
+    let start_position = Pos::new(1, 0);
+    let end_position = Pos::new(grid.width() - 2, grid.height() - 1);
 
     let mut graph = HikingGraph {
         paths: Vec::new(),
@@ -143,9 +125,9 @@ fn to_graph(grid: &Grid<Tile>) -> HikingGraph {
         let id = junctions[&current];
 
         if let Tile::Slope(dir) = grid[current] {
-            let forced_next = (current.0 + dir.offset().0, current.1 + dir.offset().1);
+            let forced_next = current + dir;
             if grid[forced_next] != Tile::Forest && !visited[forced_next] {
-                let path = trace_path(&grid, &mut visited, current, forced_next, 1);
+                let path = trace_path(grid, &mut visited, current, forced_next, 1);
                 let dest = *junctions.entry(path.1).or_insert_with(|| {
                     let junction_id = graph.junctions.len();
                     graph.junctions.push(vec![]);
@@ -160,9 +142,10 @@ fn to_graph(grid: &Grid<Tile>) -> HikingGraph {
             }
         }
 
-        for ((x, y), t) in grid.neighbors4(current.0, current.1) {
-            if t != &Tile::Forest && !visited[(x, y)] {
-                let path = trace_path(&grid, &mut visited, current, (x, y), 1);
+        for ((x, y), t) in grid.neighbors4(current.x, current.y) {
+            let next = Pos::new(x, y);
+            if t != &Tile::Forest && !visited[next] {
+                let path = trace_path(grid, &mut visited, current, next, 1);
                 let dest = *junctions.entry(path.1).or_insert_with(|| {
                     let junction_id = graph.junctions.len();
                     graph.junctions.push(vec![]);
@@ -190,16 +173,16 @@ pub struct Day23;
 impl Solution for Day23 {
     type Input<'a> = Grid<Tile>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         parsing::parse(content)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let graph = to_graph(&input);
-        format!("{}", longest_path(&graph))
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let graph = to_graph(input);
+        Ok(longest_path(&graph).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut without_slopes = input.clone();
         for y in 0..without_slopes.height() {
             for x in 0..without_slopes.width() {
@@ -210,23 +193,22 @@ impl Solution for Day23 {
         }
 
         let graph = to_graph(&without_slopes);
-        format!("{}", longest_path(&graph))
+        Ok(longest_path(&graph).into())
     }
 }
 
 mod parsing {
-    use super::{Direction, Tile};
+    use super::{Dir4, Tile};
     use crate::utils::Grid;
 
-    pub(super) fn parse(input: &str) -> Grid<Tile> {
-        Grid::parse(input, |c| match c {
-            '#' => Tile::Forest,
-            '.' => Tile::Path,
-            '>' => Tile::Slope(Direction::E),
-            '<' => Tile::Slope(Direction::W),
-            '^' => Tile::Slope(Direction::N),
-            'v' => Tile::Slope(Direction::S),
-            _ => unimplemented!(),
+    use aoc_core::AocError;
+
+    pub(super) fn parse(input: &str) -> Result<Grid<Tile>, AocError> {
+        Grid::try_parse(input, |c| match c {
+            '#' => Some(Tile::Forest),
+            '.' => Some(Tile::Path),
+            '>' | '<' | '^' | 'v' => Dir4::try_from(c).ok().map(Tile::Slope),
+            _ => None,
         })
     }
 }
@@ -36,14 +36,14 @@ fn find_ray(
     let mut rhs = nalgebra::base::DMatrix::zeros(stones.len() * 3, 1);
 
     for (i, s) in stones.iter().enumerate() {
-        matrix[(3 * i + 0, 0)] = 1 as i128;
-        matrix[(3 * i + 1, 1)] = 1 as i128;
-        matrix[(3 * i + 2, 2)] = 1 as i128;
+        matrix[(3 * i, 0)] = 1_i128;
+        matrix[(3 * i + 1, 1)] = 1_i128;
+        matrix[(3 * i + 2, 2)] = 1_i128;
 
-        matrix[(3 * i + 0, 4)] = s.v.2 as i128;
-        matrix[(3 * i + 0, 5)] = -s.v.1 as i128;
-        matrix[(3 * i + 0, 7)] = -s.p.2 as i128;
-        matrix[(3 * i + 0, 8)] = s.p.1 as i128;
+        matrix[(3 * i, 4)] = s.v.2 as i128;
+        matrix[(3 * i, 5)] = -s.v.1 as i128;
+        matrix[(3 * i, 7)] = -s.p.2 as i128;
+        matrix[(3 * i, 8)] = s.p.1 as i128;
 
         matrix[(3 * i + 1, 3)] = -s.v.2 as i128;
         matrix[(3 * i + 1, 5)] = s.v.0 as i128;
@@ -55,7 +55,7 @@ fn find_ray(
         matrix[(3 * i + 2, 6)] = -s.p.1 as i128;
         matrix[(3 * i + 2, 7)] = s.p.0 as i128;
 
-        rhs[(3 * i + 0, 0)] = (s.p.1 * s.v.2 - s.p.2 * s.v.1) as i128;
+        rhs[(3 * i, 0)] = (s.p.1 * s.v.2 - s.p.2 * s.v.1) as i128;
         rhs[(3 * i + 1, 0)] = (s.p.2 * s.v.0 - s.p.0 * s.v.2) as i128;
         rhs[(3 * i + 2, 0)] = (s.p.0 * s.v.1 - s.p.1 * s.v.0) as i128;
     }
@@ -112,17 +112,39 @@ fn find_ray(
     )
 }
 
+/// The test area of part a, spanning `min..=max` in both x and y
+#[derive(Clone, Copy, Debug)]
+pub struct TestArea {
+    min: i64,
+    max: i64,
+}
+
+impl TestArea {
+    fn new(params: &Params) -> Result<Self, AocError> {
+        params.check(&["min", "max"])?;
+        Ok(Self {
+            min: params.get("min", 200000000000000)?,
+            max: params.get("max", 400000000000000)?,
+        })
+    }
+}
+
 pub struct Day24;
 impl Solution for Day24 {
-    type Input<'a> = Vec<Hailstone>;
+    type Input<'a> = (TestArea, Vec<Hailstone>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Self::parse_with(content, &Params::new())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        const X_RANGE: std::ops::RangeInclusive<i64> = 200000000000000..=400000000000000;
-        const Y_RANGE: std::ops::RangeInclusive<i64> = 200000000000000..=400000000000000;
+    fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+        let area = TestArea::new(params)?;
+        Ok((area, finish(content, parsing::parse(content))?))
+    }
+
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (TestArea { min, max }, input) = input;
+        let area = *min..=*max;
 
         let mut count = 0;
 
@@ -135,20 +157,20 @@ impl Solution for Day24 {
                 }
 
                 let x_start = i128::min(
-                    d as i128 * (X_RANGE.start() - x.p.0) as i128,
-                    d as i128 * (X_RANGE.end() - x.p.0) as i128,
+                    d as i128 * (area.start() - x.p.0) as i128,
+                    d as i128 * (area.end() - x.p.0) as i128,
                 );
                 let x_end = i128::max(
-                    d as i128 * (X_RANGE.start() - x.p.0) as i128,
-                    d as i128 * (X_RANGE.end() - x.p.0) as i128,
+                    d as i128 * (area.start() - x.p.0) as i128,
+                    d as i128 * (area.end() - x.p.0) as i128,
                 );
                 let y_start = i128::min(
-                    d as i128 * (Y_RANGE.start() - x.p.1) as i128,
-                    d as i128 * (Y_RANGE.end() - x.p.1) as i128,
+                    d as i128 * (area.start() - x.p.1) as i128,
+                    d as i128 * (area.end() - x.p.1) as i128,
                 );
                 let y_end = i128::max(
-                    d as i128 * (Y_RANGE.start() - x.p.1) as i128,
-                    d as i128 * (Y_RANGE.end() - x.p.1) as i128,
+                    d as i128 * (area.start() - x.p.1) as i128,
+                    d as i128 * (area.end() - x.p.1) as i128,
                 );
 
                 let x_range = x_start..x_end;
@@ -162,13 +184,20 @@ impl Solution for Day24 {
             }
         }
 
-        format!("{}", count)
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let ((x, y, z), _) = find_ray(&input);
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let ((x, y, z), _) = find_ray(&input.1);
 
-        format!("{}", x + y + z)
+        let sum = x + y + z;
+        if !sum.is_integer() {
+            return Err(AocError::solve(format!(
+                "the rock starts at a fractional position, the coordinates sum to {}",
+                sum
+            )));
+        }
+        Ok(sum.to_integer().into())
     }
 }
 
@@ -198,17 +227,20 @@ mod parsing {
     }
 }
 
-// gen_test!(
-//     a,
-//     Day24,
-//     r"19, 13, 30 @ -2,  1, -2
-// 18, 19, 22 @ -1, -1, -2
-// 20, 25, 34 @ -2, -2, -4
-// 12, 31, 28 @ -1, -2, -1
-// 20, 19, 15 @  1, -5, -3
-// ",
-//     "2"
-// );
+gen_test!(
+    a,
+    Day24,
+    r"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+",
+    "2",
+    min = 7,
+    max = 27
+);
+
 gen_test!(
     b,
     Day24,
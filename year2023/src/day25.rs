@@ -66,12 +66,14 @@ fn calc_flow(
 pub struct Day25;
 impl Solution for Day25 {
     type Input<'a> = Graph<&'a str, (), Undirected>;
+    // There is no puzzle for the second part of the last day
+    const PARTS: &'static [Part] = &[Part::A];
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        parsing::parse(content).unwrap().1
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        finish(content, parsing::parse(content))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let ref_node = input.node_indices().next().unwrap();
 
         let group = input
@@ -79,11 +81,11 @@ impl Solution for Day25 {
             .filter(|&x| x == ref_node || calc_flow(input, ref_node, x) > 3)
             .collect_vec();
 
-        format!("{}", group.len() * (input.node_count() - group.len()))
+        Ok((group.len() * (input.node_count() - group.len())).into())
     }
 
-    fn part_b<'a>(_input: &Self::Input<'a>) -> String {
-        "Push the button!".to_owned()
+    fn part_b<'a>(_input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Ok(Answer::None)
     }
 }
 
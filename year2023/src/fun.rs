@@ -1,3 +1,5 @@
+use aoc_core::AocError;
+
 use crate::utils::Grid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,21 +29,20 @@ impl std::fmt::Display for GameOfLife {
 }
 
 impl GameOfLife {
-    pub fn new(board: &str) -> Self {
-        let mut board = Grid::parse(board, |c| match c {
-            '.' | ' ' => Cell::Dead,
-            'x' => Cell::Alive,
-            _ => unimplemented!(),
-        });
+    pub fn new(board: &str) -> Result<Self, AocError> {
+        let mut board = Grid::try_parse(board, |c| match c {
+            '.' | ' ' => Some(Cell::Dead),
+            'x' => Some(Cell::Alive),
+            _ => None,
+        })?;
         board.set_wrapping(true);
-        Self { board }
+        Ok(Self { board })
     }
 
     pub fn step(&mut self) {
-        let mut result = Vec::with_capacity(self.board.height() as usize);
+        let mut result = Vec::with_capacity((self.board.width() * self.board.height()) as usize);
 
         for y in 0..self.board.height() {
-            let mut row = Vec::with_capacity(self.board.width() as usize);
             for x in 0..self.board.width() {
                 let count = self
                     .board
@@ -54,14 +55,12 @@ impl GameOfLife {
                     2 => self.board[(x, y)],
                     3 => Cell::Alive,
                     4.. => Cell::Dead,
-                    _ => unreachable!(),
                 };
-                row.push(cell);
+                result.push(cell);
             }
-            result.push(row)
         }
 
-        let mut board: Grid<Cell> = result.into();
+        let mut board = Grid::from_vec(self.board.width() as usize, result);
 
         board.set_wrapping(true);
         self.board = board;
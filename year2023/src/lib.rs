@@ -1,120 +1,35 @@
 pub mod fun;
-pub mod utils;
 
-#[derive(Clone, Copy, Debug)]
-enum Part {
-    A,
-    B,
-}
-
-impl Part {
-    fn to_string(&self) -> &'static str {
-        match *self {
-            Part::A => "a",
-            Part::B => "b",
-        }
-    }
-}
-
-pub trait Solution {
-    type Input<'a>;
-
-    fn parse<'a>(content: &'a str) -> Self::Input<'a>;
-
-    fn part_a<'a>(input: &Self::Input<'a>) -> String;
-    fn part_b<'a>(input: &Self::Input<'a>) -> String;
-
-    fn solve(content: &str, part_a: bool, part_b: bool) {
-        let input = Self::parse(content);
-
-        let solve_internal = |part: Part| {
-            println!("");
-            println!("Solving part {} ...", part.to_string());
-            let timer = std::time::Instant::now();
-            let solution = match part {
-                Part::A => Self::part_a(&input),
-                Part::B => Self::part_b(&input),
-            };
-            let duration = timer.elapsed();
-            println!("... took {:?}", duration);
-            println!("");
-            println!("The Solution is:");
-            println!("{solution}");
-            println!("");
-        };
-
-        if part_a {
-            solve_internal(Part::A)
-        }
-        if part_b {
-            solve_internal(Part::B)
-        }
-    }
-}
-
-macro_rules! gen_solve {
-
-    ( $(($x:expr, $m:ident, $d:ident)),* ) => {
-
-        $(mod $m;)*
-
-        pub fn solve(day: u32, part_a: bool, part_b: bool) {
-            let content = std::fs::read_to_string(&format!("input/day{:0>2}.txt", day)).expect("unable to open input file");
-            match day {
-                $($x => {$m::$d::solve(&content, part_a, part_b);} )*
-                _ => {unimplemented!();}
-            }
-        }
-    };
-}
-
-macro_rules! gen_test {
-    (a, $d:ident, $input:expr, $output:expr) => {
-        #[cfg(test)]
-        #[test]
-        fn test_part_a() {
-            let input = $d::parse($input);
-            let output = $output;
-            let result = $d::part_a(&input);
-            assert_eq!(&result, output);
-        }
-    };
-    (b, $d:ident, $input:expr, $output:expr) => {
-        #[test]
-        #[cfg(test)]
-        fn test_part_b() {
-            let input = $d::parse($input);
-            let output = $output;
-            let result = $d::part_b(&input);
-            assert_eq!(&result, output);
-        }
-    };
-}
+use aoc_core::{finish, gen_examples, gen_solve, gen_test, Params, Part};
+pub use aoc_core::{utils, Answer, AocError, Solution};
 
 gen_solve!(
-    (01, day01, Day01),
-    (02, day02, Day02),
-    (03, day03, Day03),
-    (04, day04, Day04),
-    (05, day05, Day05),
-    (06, day06, Day06),
-    (07, day07, Day07),
-    (08, day08, Day08),
-    (09, day09, Day09),
-    (10, day10, Day10),
-    (11, day11, Day11),
-    (12, day12, Day12),
-    (13, day13, Day13),
-    (14, day14, Day14),
-    (15, day15, Day15),
-    (16, day16, Day16),
-    (17, day17, Day17),
-    (18, day18, Day18),
-    (19, day19, Day19),
-    (20, day20, Day20),
-    (21, day21, Day21),
-    (22, day22, Day22),
-    (23, day23, Day23),
-    (24, day24, Day24),
-    (25, day25, Day25)
+    2023;
+    (1, day01, Day01, "Trebuchet?!"),
+    (2, day02, Day02, "Cube Conundrum"),
+    (3, day03, Day03, "Gear Ratios"),
+    (4, day04, Day04, "Scratchcards"),
+    (5, day05, Day05, "If You Give A Seed A Fertilizer"),
+    (6, day06, Day06, "Wait For It"),
+    (7, day07, Day07, "Camel Cards"),
+    (8, day08, Day08, "Haunted Wasteland"),
+    (9, day09, Day09, "Mirage Maintenance"),
+    (10, day10, Day10, "Pipe Maze"),
+    (11, day11, Day11, "Cosmic Expansion"),
+    (12, day12, Day12, "Hot Springs"),
+    (13, day13, Day13, "Point of Incidence"),
+    (14, day14, Day14, "Parabolic Reflector Dish"),
+    (15, day15, Day15, "Lens Library"),
+    (16, day16, Day16, "The Floor Will Be Lava"),
+    (17, day17, Day17, "Clumsy Crucible"),
+    (18, day18, Day18, "Lavaduct Lagoon"),
+    (19, day19, Day19, "Aplenty"),
+    (20, day20, Day20, "Pulse Propagation"),
+    (21, day21, Day21, "Step Counter"),
+    (22, day22, Day22, "Sand Slabs"),
+    (23, day23, Day23, "A Long Walk"),
+    (24, day24, Day24, "Never Tell Me The Odds"),
+    (25, day25, Day25, "Snowverload")
 );
+
+gen_examples!();
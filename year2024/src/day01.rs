@@ -8,30 +8,30 @@ pub struct Day01;
 impl Solution for Day01 {
     type Input<'a> = (Vec<i64>, Vec<i64>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         // How the heck am I supposed to know that `upzip` automatically collects my iterators?
-        content
+        let pairs = content
             .lines()
             .map(|line| {
-                let mut sp = line.split_whitespace();
-                return (
-                    sp.next().unwrap().parse::<i64>().unwrap(),
-                    sp.next().unwrap().parse::<i64>().unwrap(),
-                );
+                let Some((a, b)) = line.split_once(char::is_whitespace) else {
+                    return Err(AocError::parse_at(content, line, "expected two numbers"));
+                };
+                Ok((number::<i64>(content, a)?, number::<i64>(content, b)?))
             })
-            .unzip()
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(pairs.into_iter().unzip())
         // Took me way to long to figure it out....
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let (mut a, mut b) = input.clone();
         a.sort();
         b.sort();
         let sum: i64 = a.iter().zip(b.iter()).map(|(&x, &y)| (x - y).abs()).sum();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let (a, b) = input;
         let mut b_map: HashMap<i64, i64> = HashMap::new();
         for b in b {
@@ -41,7 +41,7 @@ impl Solution for Day01 {
             .iter()
             .map(|&x| *b_map.get(&x).unwrap_or(&0) * x)
             .sum::<i64>();
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
@@ -4,41 +4,41 @@ pub struct Day02;
 impl Solution for Day02 {
     type Input<'a> = Vec<Vec<i64>>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         content
             .lines()
             .map(|line| {
                 line.split_whitespace()
-                    .map(|x| x.parse::<i64>().unwrap())
+                    .map(|x| number::<i64>(content, x))
                     .collect()
             })
             .collect()
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let count = input
             .iter()
             .filter(|x| {
-                is_safe(&x, true, std::cmp::Ordering::Less)
-                    || is_safe(&x, true, std::cmp::Ordering::Greater)
+                is_safe(x, true, std::cmp::Ordering::Less)
+                    || is_safe(x, true, std::cmp::Ordering::Greater)
             })
             .count();
 
-        format!("{}", count)
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let count = input
             .iter()
             .filter(|x| {
-                is_safe(&x, false, std::cmp::Ordering::Less)
-                    || is_safe(&x, false, std::cmp::Ordering::Greater)
+                is_safe(x, false, std::cmp::Ordering::Less)
+                    || is_safe(x, false, std::cmp::Ordering::Greater)
                     || is_safe(&x[1..], true, std::cmp::Ordering::Less)
                     || is_safe(&x[1..], true, std::cmp::Ordering::Greater)
             })
             .count();
 
-        format!("{}", count)
+        Ok(count.into())
     }
 }
 
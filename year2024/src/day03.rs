@@ -12,7 +12,7 @@ pub struct Day03;
 impl Solution for Day03 {
     type Input<'a> = Vec<Inst>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         use super::parser::prelude::*;
 
         #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -24,8 +24,8 @@ impl Solution for Day03 {
         }
 
         let tokenizer_digit = parser::conditional1_pos(
-            |p, c| p < 3 && c.is_digit(10),
-            |c| Token::Num(c.parse::<i64>().unwrap()),
+            |p, c| p < 3 && c.is_ascii_digit(),
+            |c| Token::Num(c.bytes().fold(0, |n, d| n * 10 + (d - b'0') as i64)),
         );
 
         let tokenizer_mul = parser::tag("mul(", Token::Mul)
@@ -42,7 +42,7 @@ impl Solution for Day03 {
 
         let (tokens, _) = tokenizer.process(content);
 
-        tokens
+        Ok(tokens
             .iter()
             .tuple_windows()
             .filter_map(|c| match c {
@@ -51,10 +51,10 @@ impl Solution for Day03 {
                 (Token::Dont, _, _) => Some(Inst::Dont),
                 _ => None,
             })
-            .collect()
+            .collect())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let sum: i64 = input
             .iter()
             .filter_map(|x| match x {
@@ -63,10 +63,10 @@ impl Solution for Day03 {
             })
             .map(|(a, b)| a * b)
             .sum();
-        format!("{sum}")
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut sum = 0;
         let mut enabled = true;
         for inst in input {
@@ -76,7 +76,7 @@ impl Solution for Day03 {
                 Inst::Dont => enabled = false,
             }
         }
-        format!("{sum}")
+        Ok(sum.into())
     }
 }
 
@@ -1,85 +1,58 @@
 use super::*;
+use crate::utils::Grid;
 
 pub struct Day04;
 impl Solution for Day04 {
-    type Input<'a> = Vec<Vec<char>>;
+    type Input<'a> = Grid<char>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        content.lines().map(|line| line.chars().collect()).collect()
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Grid::try_parse(content, Some)
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let mask1 = [[Some('X'), Some('M'), Some('A'), Some('S')]];
-        let mask2 = rotate_mask(&mask1);
-        let mask3 = rotate_mask(&mask2);
-        let mask4 = rotate_mask(&mask3);
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let straight = mask("XMAS")?;
+        let diagonal = mask(
+            "X...
+.M..
+..A.
+...S",
+        )?;
 
-        let dmask1 = [
-            [Some('X'), None, None, None],
-            [None, Some('M'), None, None],
-            [None, None, Some('A'), None],
-            [None, None, None, Some('S')],
-        ];
-        let dmask2 = rotate_mask(&dmask1);
-        let dmask3 = rotate_mask(&dmask2);
-        let dmask4 = rotate_mask(&dmask3);
-
-        let count = fold_2d(input.as_slice(), &mask1)
-            + fold_2d(input.as_slice(), &mask2)
-            + fold_2d(input.as_slice(), &mask3)
-            + fold_2d(input.as_slice(), &mask4)
-            + fold_2d(input.as_slice(), &dmask1)
-            + fold_2d(input.as_slice(), &dmask2)
-            + fold_2d(input.as_slice(), &dmask3)
-            + fold_2d(input.as_slice(), &dmask4);
-
-        format!("{}", count)
+        Ok((count(input, &straight) + count(input, &diagonal)).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let mask1 = [
-            [Some('M'), None, Some('S')],
-            [None, Some('A'), None],
-            [Some('M'), None, Some('S')],
-        ];
-        let mask2 = rotate_mask(&mask1);
-        let mask3 = rotate_mask(&mask2);
-        let mask4 = rotate_mask(&mask3);
-
-        let count = fold_2d(input.as_slice(), &mask1)
-            + fold_2d(input.as_slice(), &mask2)
-            + fold_2d(input.as_slice(), &mask3)
-            + fold_2d(input.as_slice(), &mask4);
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let cross = mask(
+            "M.S
+.A.
+M.S",
+        )?;
 
-        format!("{}", count)
+        Ok(count(input, &cross).into())
     }
 }
 
-fn rotate_mask<const N: usize, const M: usize>(
-    mask: &[[Option<char>; M]; N],
-) -> [[Option<char>; N]; M] {
-    std::array::from_fn(|i| std::array::from_fn(|j| mask[N - j - 1][i]))
+/// A pattern to search for, `.` matches any character.
+fn mask(pattern: &str) -> Result<Grid<Option<char>>, AocError> {
+    Grid::try_parse(pattern, |c| Some(Some(c).filter(|&c| c != '.')))
 }
 
-fn fold_2d<const N: usize, const M: usize>(
-    input: &[Vec<char>],
-    mask: &[[Option<char>; M]; N],
-) -> usize {
-    if input.len() < mask.len() {
-        return 0;
-    }
-
-    (0..=(input[0].len() - mask[0].len()))
-        .filter(|&j| {
-            input.iter().zip(mask.iter()).all(|(iline, &mline)| {
-                iline.as_slice()[j..]
-                    .iter()
-                    .zip(mline.iter())
-                    .all(|(&i, &m)| m.is_none_or(|m| i == m))
-            })
+/// Counts the windows of `input` matching `mask` in any of its four rotations.
+fn count(input: &Grid<char>, mask: &Grid<Option<char>>) -> usize {
+    std::iter::successors(Some(mask.clone()), |mask| Some(mask.rotate_cw()))
+        .take(4)
+        .map(|mask| {
+            input
+                .windows(mask.width() as usize, mask.height() as usize)
+                .filter(|window| {
+                    window
+                        .into_iter()
+                        .zip(mask.as_slice())
+                        .all(|((_, &c), m)| m.is_none_or(|m| c == m))
+                })
+                .count()
         })
-        .count()
-        + fold_2d(&input[1..], mask)
+        .sum()
 }
 
 gen_test!(
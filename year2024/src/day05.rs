@@ -12,28 +12,31 @@ pub struct Day05;
 impl Solution for Day05 {
     type Input<'a> = PageUpdates;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         let lines = content.lines();
         let mut ordering = HashSet::new();
 
-        for line in lines.take_while(|l| l.len() > 0) {
-            let mut parts = line.split('|');
-            ordering.insert((
-                parts.next().unwrap().parse().unwrap(),
-                parts.next().unwrap().parse().unwrap(),
-            ));
+        for line in lines.take_while(|l| !l.is_empty()) {
+            let Some((before, after)) = line.split_once('|') else {
+                return Err(AocError::parse_at(
+                    content,
+                    line,
+                    "expected an ordering rule `X|Y`",
+                ));
+            };
+            ordering.insert((number(content, before)?, number(content, after)?));
         }
 
-        let rest = content.lines().skip_while(|l| l.len() > 0).skip(1);
+        let rest = content.lines().skip_while(|l| !l.is_empty()).skip(1);
 
         let updates = rest
-            .map(|line| line.split(',').map(|x| x.parse().unwrap()).collect())
-            .collect();
+            .map(|line| line.split(',').map(|x| number(content, x)).collect())
+            .collect::<Result<_, _>>()?;
 
-        PageUpdates { ordering, updates }
+        Ok(PageUpdates { ordering, updates })
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let res: u32 = input
             .updates
             .iter()
@@ -46,21 +49,21 @@ impl Solution for Day05 {
                         }
                     }
                 }
-                return true;
+                true
             })
             .map(|u| u[u.len() / 2])
             .sum();
 
-        format!("{}", res)
+        Ok(res.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let res: u32 = input
             .updates
             .iter()
             .filter_map(|u| {
                 let mut update = u.clone();
-                if order(&input, &mut update) {
+                if order(input, &mut update) {
                     return None;
                 }
                 Some(update)
@@ -68,7 +71,7 @@ impl Solution for Day05 {
             .map(|u| u[u.len() / 2])
             .sum();
 
-        format!("{}", res)
+        Ok(res.into())
     }
 }
 
@@ -83,7 +86,7 @@ fn order(input: &PageUpdates, update: &mut Vec<u32>) -> bool {
             }
         }
     }
-    return true;
+    true
 }
 
 gen_test!(
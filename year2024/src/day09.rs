@@ -6,7 +6,7 @@ pub struct Day09;
 impl Solution for Day09 {
     type Input<'a> = Vec<(Option<u64>, u64)>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         let mut id = 0;
         let ids = std::iter::from_fn(move || {
             id += 1;
@@ -16,10 +16,10 @@ impl Solution for Day09 {
         let counts = content
             .chars()
             .filter_map(|c| c.to_digit(10).map(|x| x as u64));
-        ids.zip(counts).collect()
+        Ok(ids.zip(counts).collect())
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut input = input.clone();
         let mut l = 0;
         let mut r = input.len() - 1;
@@ -65,13 +65,10 @@ impl Solution for Day09 {
         })
         .flatten();
 
-        format!(
-            "{}",
-            filled.enumerate().map(|(x, y)| x as u64 * y).sum::<u64>()
-        )
+        Ok(filled.enumerate().map(|(x, y)| x as u64 * y).sum::<u64>().into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut input = input.clone();
 
         let mut r = input.len() - 1;
@@ -97,13 +94,12 @@ impl Solution for Day09 {
 
         let sum = input
             .iter()
-            .map(|(t, c)| std::iter::repeat_n(t.unwrap_or(0), *c as usize))
-            .flatten()
+            .flat_map(|(t, c)| std::iter::repeat_n(t.unwrap_or(0), *c as usize))
             .enumerate()
             .map(|(x, y)| x as u64 * y)
             .sum::<u64>();
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
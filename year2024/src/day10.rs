@@ -8,14 +8,22 @@ pub struct Day10;
 impl Solution for Day10 {
     type Input<'a> = Vec<Vec<u32>>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         content
             .lines()
-            .map(|line| line.chars().map(|x| x.to_digit(10).unwrap()).collect_vec())
-            .collect_vec()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, x)| {
+                        x.to_digit(10).ok_or_else(|| {
+                            AocError::parse_at(content, &line[i..], "expected a height")
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut reachable = input
             .iter()
             .map(|line| line.iter().map(|_| HashSet::new()).collect_vec())
@@ -63,10 +71,10 @@ impl Solution for Day10 {
             }
         }
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut possibilities = input
             .iter()
             .map(|line| line.iter().map(|_| 0).collect_vec())
@@ -110,7 +118,7 @@ impl Solution for Day10 {
             }
         }
 
-        format!("{}", sum)
+        Ok(sum.into())
     }
 }
 
@@ -6,31 +6,31 @@ pub struct Day11;
 impl Solution for Day11 {
     type Input<'a> = Vec<u64>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         content
             .split_whitespace()
-            .map(|x| x.parse::<u64>().unwrap())
+            .map(|x| number::<u64>(content, x))
             .collect()
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut lookup = HashMap::new();
         let mut count = 0;
         for x in input {
             count += number_stones(*x, 25, &mut lookup);
         }
 
-        format!("{}", count)
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut lookup = HashMap::new();
         let mut count = 0;
         for x in input {
             count += number_stones(*x, 75, &mut lookup);
         }
 
-        format!("{}", count)
+        Ok(count.into())
     }
 }
 
@@ -44,7 +44,7 @@ fn number_stones(x: u64, r: u64, lookup: &mut HashMap<(u64, u64), u64>) -> u64 {
     let l10 = log10(x);
     let v = if x == 0 {
         number_stones(1, r - 1, lookup)
-    } else if l10 % 2 == 0 {
+    } else if l10.is_multiple_of(2) {
         let a = exp10(l10 / 2);
         number_stones(x / a, r - 1, lookup) + number_stones(x % a, r - 1, lookup)
     } else {
@@ -58,14 +58,14 @@ fn exp10(x: u64) -> u64 {
     if x == 0 {
         return 1;
     }
-    return 10 * exp10(x - 1);
+    10 * exp10(x - 1)
 }
 
 fn log10(x: u64) -> u64 {
     if x < 10 {
         return 1;
     }
-    return log10(x / 10) + 1;
+    log10(x / 10) + 1
 }
 
 gen_test!(a, Day11, r"125 17", "55312");
@@ -1,6 +1,5 @@
 use super::*;
 
-use itertools::Itertools;
 use regex::Regex;
 
 #[derive(Debug, Clone)]
@@ -14,22 +13,28 @@ pub struct Day13;
 impl Solution for Day13 {
     type Input<'a> = Vec<ClawSetup>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         let re = Regex::new(
-            r"Button A: X\+([0-9]+), Y\+([0-9]+)\s+Button B: X\+([0-9]+), Y\+([0-9]+)\s+Prize: X=([0-9]+), Y=([0-9]+)"
+            r"^Button A: X\+([0-9]+), Y\+([0-9]+)\s+Button B: X\+([0-9]+), Y\+([0-9]+)\s+Prize: X=([0-9]+), Y=([0-9]+)\s*$"
         ).unwrap();
 
-        re.captures_iter(content)
-            .map(|c| c.extract())
-            .map(|(_, [ax, ay, bx, by, px, py])| ClawSetup {
-                a: (ax.parse().unwrap(), ay.parse().unwrap()),
-                b: (bx.parse().unwrap(), by.parse().unwrap()),
-                prize: (px.parse().unwrap(), py.parse().unwrap()),
+        content
+            .split("\n\n")
+            .map(|block| {
+                let Some(c) = re.captures(block) else {
+                    return Err(AocError::parse_at(content, block, "expected a claw machine"));
+                };
+                let (_, [ax, ay, bx, by, px, py]) = c.extract();
+                Ok(ClawSetup {
+                    a: (number(content, ax)?, number(content, ay)?),
+                    b: (number(content, bx)?, number(content, by)?),
+                    prize: (number(content, px)?, number(content, py)?),
+                })
             })
-            .collect_vec()
+            .collect()
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let total_cost = input
             .iter()
             .map(|setup| {
@@ -48,14 +53,14 @@ impl Solution for Day13 {
                 if sol[0] <= 0 || sol[1] <= 0 {
                     return 0;
                 }
-                return sol[0] * 3 + sol[1];
+                sol[0] * 3 + sol[1]
             })
             .sum::<i64>();
 
-        format!("{}", total_cost)
+        Ok(total_cost.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let total_cost = input
             .iter()
             .map(|setup| {
@@ -77,11 +82,11 @@ impl Solution for Day13 {
                 if sol[0] <= 0 || sol[1] <= 0 {
                     return 0;
                 }
-                return sol[0] * 3 + sol[1];
+                sol[0] * 3 + sol[1]
             })
             .sum::<i64>();
 
-        format!("{}", total_cost)
+        Ok(total_cost.into())
     }
 }
 
@@ -3,29 +3,58 @@ use regex::Regex;
 
 use super::*;
 
+type Robot = ((i64, i64), (i64, i64));
+
+/// The size of the area the robots move in
+#[derive(Clone, Copy, Debug)]
+pub struct Room {
+    width: i64,
+    height: i64,
+}
+
+impl Room {
+    fn new(params: &Params) -> Result<Self, AocError> {
+        params.check(&["width", "height"])?;
+        Ok(Self {
+            width: params.get("width", 101)?,
+            height: params.get("height", 103)?,
+        })
+    }
+}
+
 pub struct Day14;
 impl Solution for Day14 {
-    type Input<'a> = Vec<((i64, i64), (i64, i64))>;
-
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        let re = Regex::new(r"p=([0-9]+),([0-9]+) v=(-?[0-9]+),(-?[0-9]+)").unwrap();
-
-        re.captures_iter(content)
-            .map(|c| c.extract())
-            .map(|(_, [px, py, vx, vy])| {
-                (
-                    (px.parse().unwrap(), py.parse().unwrap()),
-                    (vx.parse().unwrap(), vy.parse().unwrap()),
-                )
+    type Input<'a> = (Room, Vec<Robot>);
+
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Self::parse_with(content, &Params::new())
+    }
+
+    fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+        let room = Room::new(params)?;
+        let re = Regex::new(r"^p=([0-9]+),([0-9]+) v=(-?[0-9]+),(-?[0-9]+)$").unwrap();
+
+        let robots = content
+            .lines()
+            .map(|line| {
+                let Some(c) = re.captures(line) else {
+                    return Err(AocError::parse_at(content, line, "expected a robot"));
+                };
+                let (_, [px, py, vx, vy]) = c.extract();
+                Ok((
+                    (number(content, px)?, number(content, py)?),
+                    (number(content, vx)?, number(content, vy)?),
+                ))
             })
-            .collect_vec()
+            .collect::<Result<_, _>>()?;
+        Ok((room, robots))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let width = 101;
-        let height = 103;
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (Room { width, height }, input) = input;
+        let (width, height) = (*width, *height);
 
-        let final_positions = advance_robots(&input, width, height, 100);
+        let final_positions = advance_robots(input, width, height, 100);
         let mut counter = [[0, 0], [0, 0]];
         for (x, y) in final_positions {
             let x_idx = if x < width / 2 {
@@ -45,46 +74,37 @@ impl Solution for Day14 {
             counter[x_idx][y_idx] += 1;
         }
 
-        format!(
-            "{}",
-            counter[0][0] * counter[0][1] * counter[1][0] * counter[1][1]
-        )
+        Ok((counter[0][0] * counter[0][1] * counter[1][0] * counter[1][1]).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        for seconds in 0.. {
-            let mut grid = [[false; 101]; 103];
-            let positions = advance_robots(&input, 101, 103, seconds);
-            for pos in &positions {
-                grid[pos.1 as usize][pos.0 as usize] = true;
-            }
-            // Quick Entropy Check
-            // A Christmas tree should have a low score
-            // The area is divided into 10 x 10 small sections and we count the number of robots in
-            // each segment. If the robots are gathered in one area, then only a few sectors
-            // should have a lot of robots in them.
-            let score: u64 = downsample::<10, 10>(&positions, 101, 103)
-                .iter()
-                .map(|line| line.iter().map(|&x| (x + 1).ilog2()).sum::<u32>() as u64)
-                .sum();
-            if score < 160 {
-                println!("========== {} seconds ==========", seconds);
-                println!(
-                    "{}",
-                    grid.map(|line| { line.map(|x| if x { "#" } else { " " }).join("") })
-                        .join("\n")
-                );
-                println!("");
-                std::thread::sleep(std::time::Duration::from_millis(1000));
-            }
-        }
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (Room { width, height }, input) = input;
+        let (width, height) = (*width, *height);
 
-        format!("{}", 0)
+        // The robots return to their initial positions after `width * height` seconds, so the tree
+        // has to show up within one period.
+        (0..width * height)
+            .map(|seconds| {
+                let positions = advance_robots(input, width, height, seconds);
+                // Quick Entropy Check
+                // A Christmas tree should have a low score
+                // The area is divided into 10 x 10 small sections and we count the number of robots
+                // in each segment. If the robots are gathered in one area, then only a few sectors
+                // should have a lot of robots in them.
+                let score: u64 = downsample::<10, 10>(&positions, width, height)
+                    .iter()
+                    .map(|line| line.iter().map(|&x| (x + 1).ilog2()).sum::<u32>() as u64)
+                    .sum();
+                (score, seconds)
+            })
+            .find(|&(score, _)| score < 160)
+            .map(|(_, seconds)| seconds.into())
+            .ok_or_else(|| AocError::solve("the robots never arrange into a christmas tree"))
     }
 }
 
 fn advance_robots(
-    robots: &[((i64, i64), (i64, i64))],
+    robots: &[Robot],
     width: i64,
     height: i64,
     seconds: i64,
@@ -127,5 +147,7 @@ p=9,3 v=2,3
 p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3",
-    "21"
+    "12",
+    width = 11,
+    height = 7
 );
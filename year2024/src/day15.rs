@@ -1,4 +1,4 @@
-use utils::Grid;
+use utils::{Dir4, Grid, Pos};
 
 use super::*;
 
@@ -27,38 +27,22 @@ impl std::fmt::Display for Tile {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    #[inline(always)]
-    fn offset(&self) -> (isize, isize) {
-        match *self {
-            Direction::Up => (0, -1),
-            Direction::Down => (0, 1),
-            Direction::Left => (-1, 0),
-            Direction::Right => (1, 0),
-        }
-    }
-}
-
 pub struct Day15;
 impl Solution for Day15 {
-    type Input<'a> = (Grid<Tile>, (isize, isize), Vec<Direction>);
-
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        let (map, seq) = content.split_once("\n\n").unwrap();
-        let (map, pos) = parse::map(map);
+    type Input<'a> = (Grid<Tile>, Pos, Vec<Dir4>);
+
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let Some((map, seq)) = content.split_once("\n\n") else {
+            return Err(AocError::parse(
+                "expected the map and the moves separated by an empty line",
+            ));
+        };
+        let (map, pos) = parse::map(map)?;
         let seq = parse::moves(seq);
-        (map, pos, seq)
+        Ok((map, pos, seq))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let mut map = input.0.clone();
         let mut pos = input.1;
         let seq = input.2.iter();
@@ -73,10 +57,10 @@ impl Solution for Day15 {
             }
         }
 
-        format!("{}", coord_sum)
+        Ok(coord_sum.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let base_map = input.0.clone();
         let mut map = Grid::new(
             base_map.width() as usize * 2,
@@ -89,8 +73,7 @@ impl Solution for Day15 {
                 _ => unreachable!(),
             },
         );
-        let pos = input.1;
-        let mut pos = (pos.0 * 2, pos.1);
+        let mut pos = Pos::new(input.1.x * 2, input.1.y);
         let seq = input.2.iter();
         for &dir in seq {
             move_robot(&mut map, &mut pos, dir);
@@ -103,110 +86,79 @@ impl Solution for Day15 {
             }
         }
 
-        format!("{}", coord_sum)
+        Ok(coord_sum.into())
     }
 }
 
-fn move_robot(map: &mut Grid<Tile>, pos: &mut (isize, isize), dir: Direction) {
+fn move_robot(map: &mut Grid<Tile>, pos: &mut Pos, dir: Dir4) {
     if can_move(map, *pos, dir) {
         apply_force(map, *pos, dir);
-        let (dx, dy) = dir.offset();
-        *pos = (pos.0 + dx, pos.1 + dy);
+        *pos += dir;
     }
 }
 
-fn can_move(map: &Grid<Tile>, pos: (isize, isize), dir: Direction) -> bool {
-    let (x, y) = pos;
-    let (dx, dy) = dir.offset();
-    match map[(x + dx, y + dy)] {
-        Tile::Box => can_move(map, (x + dx, y + dy), dir),
-        Tile::BoxL => {
-            if dx == 0 {
-                can_move(map, (x, y + dy), dir) && can_move(map, (x + 1, y + dy), dir)
-            } else {
-                can_move(map, (x + dx, y), dir)
-            }
+fn can_move(map: &Grid<Tile>, pos: Pos, dir: Dir4) -> bool {
+    let next = pos + dir;
+    match map[next] {
+        Tile::BoxL if dir.is_vertical() => {
+            can_move(map, next, dir) && can_move(map, next + Dir4::E, dir)
         }
-        Tile::BoxR => {
-            if dx == 0 {
-                can_move(map, (x, y + dy), dir) && can_move(map, (x - 1, y + dy), dir)
-            } else {
-                can_move(map, (x + dx, y), dir)
-            }
+        Tile::BoxR if dir.is_vertical() => {
+            can_move(map, next, dir) && can_move(map, next + Dir4::W, dir)
         }
+        Tile::Box | Tile::BoxL | Tile::BoxR => can_move(map, next, dir),
         Tile::Space => true,
         Tile::Wall => false,
     }
 }
 
-fn apply_force(map: &mut Grid<Tile>, pos: (isize, isize), dir: Direction) {
-    let (x, y) = pos;
-    let (dx, dy) = dir.offset();
-    match map[(x + dx, y + dy)] {
-        Tile::Box => apply_force(map, (x + dx, y + dy), dir),
-        Tile::BoxL => {
-            if dx == 0 {
-                apply_force(map, (x, y + dy), dir);
-                apply_force(map, (x + 1, y + dy), dir);
-            } else {
-                apply_force(map, (x + dx, y), dir);
-            }
+fn apply_force(map: &mut Grid<Tile>, pos: Pos, dir: Dir4) {
+    let next = pos + dir;
+    match map[next] {
+        Tile::BoxL if dir.is_vertical() => {
+            apply_force(map, next, dir);
+            apply_force(map, next + Dir4::E, dir);
         }
-        Tile::BoxR => {
-            if dx == 0 {
-                apply_force(map, (x, y + dy), dir);
-                apply_force(map, (x - 1, y + dy), dir);
-            } else {
-                apply_force(map, (x + dx, y), dir);
-            }
+        Tile::BoxR if dir.is_vertical() => {
+            apply_force(map, next, dir);
+            apply_force(map, next + Dir4::W, dir);
         }
+        Tile::Box | Tile::BoxL | Tile::BoxR => apply_force(map, next, dir),
         _ => {}
     }
-    map[(x + dx, y + dy)] = map[pos];
+    map[next] = map[pos];
     map[pos] = Tile::Space
 }
 
 mod parse {
     use itertools::Itertools;
 
-    use super::{utils::Grid, Direction, Tile};
-
-    pub fn map(content: &str) -> (Grid<Tile>, (isize, isize)) {
-        let mut pos = (1, 1);
-        let raw_map = content
-            .lines()
-            .enumerate()
-            .map(|(i, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(j, c)| {
-                        if c == '#' {
-                            Tile::Wall
-                        } else if c == 'O' {
-                            Tile::Box
-                        } else if c == '@' {
-                            pos = (j as isize, i as isize);
-                            Tile::Space
-                        } else {
-                            Tile::Space
-                        }
-                    })
-                    .collect_vec()
-            })
-            .collect_vec();
-        (Grid::from(raw_map), pos)
+    use super::{utils::Grid, AocError, Dir4, Pos, Tile};
+
+    pub fn map(content: &str) -> Result<(Grid<Tile>, Pos), AocError> {
+        let tiles = Grid::try_parse(content, |c| match c {
+            '#' | 'O' | '@' | '.' => Some(c),
+            _ => None,
+        })?;
+        let Some((pos, _)) = tiles.into_iter().find(|(_, &c)| c == '@') else {
+            return Err(AocError::parse("the robot `@` is missing"));
+        };
+        let map = Grid::new(
+            tiles.width() as usize,
+            tiles.height() as usize,
+            |x, y| match tiles[(x, y)] {
+                '#' => Tile::Wall,
+                'O' => Tile::Box,
+                _ => Tile::Space,
+            },
+        );
+        Ok((map, Pos::new(pos.0, pos.1)))
     }
 
-    pub fn moves(content: &str) -> Vec<Direction> {
+    pub fn moves(content: &str) -> Vec<Dir4> {
         content
             .chars()
-            .filter_map(|c| match c {
-                '^' => Some(Direction::Up),
-                'v' => Some(Direction::Down),
-                '<' => Some(Direction::Left),
-                '>' => Some(Direction::Right),
-                _ => None,
-            })
+            .filter_map(|c| Dir4::try_from(c).ok())
             .collect_vec()
     }
 }
@@ -26,7 +26,7 @@ impl Instruction {
             5 => Out,
             6 => Bdv,
             7 => Cdv,
-            _ => panic!("Invalid Code"),
+            _ => unreachable!("the program only holds 3-bit numbers after parsing"),
         }
     }
 }
@@ -45,11 +45,11 @@ impl Operand {
         self.value as u64
     }
 
-    fn combo(&self, registers: &[u64]) -> u64 {
-        if self.value < 4 {
-            self.value as u64
-        } else {
-            registers[(self.value - 4) as usize]
+    fn combo(&self, registers: &[u64]) -> Result<u64, AocError> {
+        match self.value {
+            0..=3 => Ok(self.value as u64),
+            4..=6 => Ok(registers[(self.value - 4) as usize]),
+            _ => Err(AocError::solve("combo operand 7 is reserved")),
         }
     }
 }
@@ -58,30 +58,39 @@ pub struct Day17;
 impl Solution for Day17 {
     type Input<'a> = ([u64; 3], Vec<u8>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         let re = regex::Regex::new(r"Register A: ([0-9]+)\nRegister B: ([0-9]+)\nRegister C: ([0-9]+)\n\nProgram: ([0-9,]+)").unwrap();
-        let (_, [a, b, c, instr]) = re.captures(content).unwrap().extract();
-        let a = a.parse::<u64>().unwrap();
-        let b = b.parse::<u64>().unwrap();
-        let c = c.parse::<u64>().unwrap();
+        let Some(c) = re.captures(content) else {
+            return Err(AocError::parse("expected three registers followed by a program"));
+        };
+        let (_, [a, b, c, instr]) = c.extract();
+        let a = number::<u64>(content, a)?;
+        let b = number::<u64>(content, b)?;
+        let c = number::<u64>(content, c)?;
         let instrs = instr
             .split(',')
-            .map(|x| x.parse::<u8>().unwrap())
-            .collect_vec();
-        ([a, b, c], instrs)
+            .map(|x| match number::<u8>(content, x)? {
+                code @ 0..=7 => Ok(code),
+                _ => Err(AocError::parse_at(content, x, "expected a 3-bit number")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(([a, b, c], instrs))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let registers = input.0.clone();
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let registers = input.0;
         let instrs = get_instructions(&input.1);
 
-        let output = run_program(registers, &instrs).collect_vec();
+        let output = run_program(registers, &instrs).collect::<Result<Vec<_>, _>>()?;
 
-        format!("{}", output.iter().map(|x| format!("{x}")).join(","))
+        Ok(output.iter().map(|x| format!("{x}")).join(",").into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        format!("{:?}", reverse(&input.1).iter().min().unwrap())
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        match reverse(&input.1).into_iter().min() {
+            Some(a) => Ok(a.into()),
+            None => Err(AocError::solve("no value of register A reproduces the program")),
+        }
     }
 }
 
@@ -106,23 +115,29 @@ fn reverse(out: &[u8]) -> Vec<u64> {
             }
         }
     }
-    return res;
+    res
 }
 
 fn run_program<'a>(
     mut registers: [u64; 3],
     instrs: &'a [(Instruction, Operand)],
-) -> impl Iterator<Item = u8> + 'a {
+) -> impl Iterator<Item = Result<u8, AocError>> + 'a {
     let mut iptr = 0;
     let step = move || {
         while iptr < instrs.len() {
-            let (niptr, out) = exec(instrs[iptr].0, instrs[iptr].1, &mut registers, iptr);
+            let (niptr, out) = match exec(instrs[iptr].0, instrs[iptr].1, &mut registers, iptr) {
+                Ok(step) => step,
+                Err(e) => {
+                    iptr = instrs.len();
+                    return Some(Err(e));
+                }
+            };
             iptr = niptr;
             if let Some(out) = out {
-                return Some(out);
+                return Some(Ok(out));
             }
         }
-        return None;
+        None
     };
     std::iter::from_fn(step)
 }
@@ -140,22 +155,30 @@ fn exec(
     op: Operand,
     registers: &mut [u64; 3],
     iptr: usize,
-) -> (usize, Option<u8>) {
+) -> Result<(usize, Option<u8>), AocError> {
     match instr {
-        Instruction::Adv => registers[0] = registers[0] >> op.combo(registers),
-        Instruction::Bxl => registers[1] = registers[1] ^ (op.literal() as u64),
-        Instruction::Bst => registers[1] = op.combo(registers) & 0x7,
+        Instruction::Adv => registers[0] = div(registers[0], op.combo(registers)?),
+        Instruction::Bxl => registers[1] ^= op.literal(),
+        Instruction::Bst => registers[1] = op.combo(registers)? & 0x7,
         Instruction::Jnz => {
             if registers[0] != 0 {
-                return (op.literal() as usize, None);
+                return Ok((op.literal() as usize, None));
             }
         }
-        Instruction::Bxc => registers[1] = registers[1] ^ registers[2],
-        Instruction::Out => return (iptr + 2, Some((op.combo(registers) % 8) as u8)),
-        Instruction::Bdv => registers[1] = registers[0] >> op.combo(registers),
-        Instruction::Cdv => registers[2] = registers[0] >> op.combo(registers),
+        Instruction::Bxc => registers[1] ^= registers[2],
+        Instruction::Out => return Ok((iptr + 2, Some((op.combo(registers)? % 8) as u8))),
+        Instruction::Bdv => registers[1] = div(registers[0], op.combo(registers)?),
+        Instruction::Cdv => registers[2] = div(registers[0], op.combo(registers)?),
     }
-    return (iptr + 2, None);
+    Ok((iptr + 2, None))
+}
+
+/// `a / 2^exp`, which is 0 once the divisor outgrows a `u64`.
+fn div(a: u64, exp: u64) -> u64 {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|exp| a.checked_shr(exp))
+        .unwrap_or(0)
 }
 
 gen_test!(
@@ -1,59 +1,80 @@
-use std::collections::BinaryHeap;
-
-use itertools::Itertools;
 use union_find::{QuickUnionUf, UnionByRank, UnionFind};
-use utils::Grid;
+use utils::path::astar;
+use utils::{Grid, Pos};
 
 use super::*;
 
+/// The memory space spans `0..=size` in both directions, `bytes` is the number of fallen bytes in
+/// part a.
+#[derive(Clone, Copy, Debug)]
+pub struct Memory {
+    size: usize,
+    bytes: usize,
+}
+
+impl Memory {
+    fn new(params: &Params) -> Result<Self, AocError> {
+        params.check(&["size", "bytes"])?;
+        Ok(Self {
+            size: params.get("size", 70)?,
+            bytes: params.get("bytes", 1024)?,
+        })
+    }
+}
+
 pub struct Day18;
 impl Solution for Day18 {
-    type Input<'a> = Vec<(isize, isize)>;
+    type Input<'a> = (Memory, Vec<(isize, isize)>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        content
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Self::parse_with(content, &Params::new())
+    }
+
+    fn parse_with<'a>(content: &'a str, params: &Params) -> Result<Self::Input<'a>, AocError> {
+        let memory = Memory::new(params)?;
+        let bytes = content
             .lines()
             .map(|line| {
-                let (x, y) = line.split_once(",").unwrap();
-                (x.parse().unwrap(), y.parse().unwrap())
+                let Some((x, y)) = line.split_once(",") else {
+                    return Err(AocError::parse_at(content, line, "expected a position `X,Y`"));
+                };
+                Ok((number(content, x)?, number(content, y)?))
             })
-            .collect_vec()
+            .collect::<Result<_, _>>()?;
+        Ok((memory, bytes))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        let (size, byte_count) = if cfg!(not(test)) {
-            (70usize, 1024usize)
-        } else {
-            (6usize, 12usize)
-        };
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (Memory { size, bytes }, input) = input;
+        let size = *size;
 
         let mut grid = Grid::new(size + 1, size + 1, |_, _| false);
-        for &(x, y) in input.iter().take(byte_count) {
+        for &(x, y) in input.iter().take(*bytes) {
             grid[(x, y)] = true;
         }
 
-        let mut dist = Grid::new(size + 1, size + 1, |_, _| u32::MAX);
-        dist[(size, size)] = 0;
-        let mut queue = BinaryHeap::new();
-        queue.push((0, size as isize, size as isize));
-
-        while dist[(0isize, 0isize)] == u32::MAX {
-            let (_, x, y) = queue.pop().unwrap();
-            let d = dist[(x, y)];
-            for (nx, ny) in dist.neighborpos4(x, y) {
-                let nd = dist[(nx, ny)];
-                if !grid[(nx, ny)] && nd > d + 1 {
-                    dist[(nx, ny)] = d + 1;
-                    queue.push((-nx - ny - (d as isize) - 1, nx, ny))
-                }
-            }
-        }
-
-        format!("{}", dist[(0isize, 0isize)])
+        let exit = Pos::ORIGIN;
+        let paths = astar(
+            [Pos::new(size as isize, size as isize)],
+            |&pos| {
+                pos.neighbors4()
+                    .into_iter()
+                    .filter(|&next| grid.get_pos(next) == Some(&false))
+                    .map(|next| (next, 1))
+            },
+            |&pos| pos.manhattan(exit),
+            |&pos| pos == exit,
+        );
+
+        let steps = paths
+            .cost()
+            .ok_or_else(|| AocError::solve("the exit can't be reached"))?;
+        Ok(steps.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        let size = if cfg!(not(test)) { 70usize } else { 6usize };
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        let (Memory { size, .. }, input) = input;
+        let size = *size;
         let idx = |x, y| x as usize + (size + 1) * y as usize;
 
         let mut grid = Grid::new(size + 1, size + 1, |_, _| false);
@@ -79,11 +100,11 @@ impl Solution for Day18 {
             }
 
             if components.find(0) == components.find((size + 1) * (size + 1) - 1) {
-                return format!("{},{}", x, y);
+                return Ok(format!("{},{}", x, y).into());
             }
         }
 
-        unreachable!()
+        Err(AocError::solve("the exit is never cut off"))
     }
 }
 
@@ -115,7 +136,9 @@ gen_test!(
 0,5
 1,6
 2,0",
-    "22"
+    "22",
+    size = 6,
+    bytes = 12
 );
 
 gen_test!(
@@ -146,5 +169,6 @@ gen_test!(
 0,5
 1,6
 2,0",
-    "6,1"
+    "6,1",
+    size = 6
 );
@@ -6,32 +6,35 @@ pub struct Day19;
 impl Solution for Day19 {
     type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
         let mut lines = content.lines();
-        let available = lines.next().unwrap().split(", ").collect_vec();
+        let Some(available) = lines.next() else {
+            return Err(AocError::parse("expected the available towel patterns"));
+        };
+        let available = available.split(", ").collect_vec();
 
         let patterns = lines.skip(1).collect_vec();
 
-        (available, patterns)
+        Ok((available, patterns))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let (available, pattern) = input;
 
         let count = pattern
             .iter()
-            .filter(|x| constructable(&available, x) > 0)
+            .filter(|x| constructable(available, x) > 0)
             .count();
 
-        format!("{}", count)
+        Ok(count.into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let (available, pattern) = input;
 
-        let count: u64 = pattern.iter().map(|x| constructable(&available, x)).sum();
+        let count: u64 = pattern.iter().map(|x| constructable(available, x)).sum();
 
-        format!("{}", count)
+        Ok(count.into())
     }
 }
 
@@ -41,15 +44,14 @@ fn constructable(available: &[&str], pattern: &str) -> u64 {
 
     for i in 1..=pattern.len() {
         for towel in available {
-            if i >= towel.len() {
-                if pattern[(i - towel.len())..]
+            if i >= towel.len()
+                && pattern[(i - towel.len())..]
                     .bytes()
                     .zip(towel.bytes())
                     .all(|(x, y)| x == y)
                 {
                     prior[i] += prior[i - towel.len()];
                 }
-            }
         }
     }
 
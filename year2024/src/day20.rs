@@ -7,42 +7,34 @@ pub struct Day20;
 impl Solution for Day20 {
     type Input<'a> = (Grid<bool>, (isize, isize), (isize, isize));
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        let mut end = (0isize, 0isize);
-        let mut start = (0isize, 0isize);
-        let raw_grid = content
-            .lines()
-            .enumerate()
-            .map(|(j, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(i, c)| {
-                        if c == 'E' {
-                            end = (i as isize, j as isize);
-                            true
-                        } else if c == 'S' {
-                            start = (i as isize, j as isize);
-                            true
-                        } else if c == '.' {
-                            true
-                        } else {
-                            false
-                        }
-                    })
-                    .collect_vec()
-            })
-            .collect_vec();
-        (Grid::from(raw_grid), start, end)
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        let tiles = Grid::try_parse(content, |c| match c {
+            '#' | '.' | 'S' | 'E' => Some(c),
+            _ => None,
+        })?;
+        let find = |tile: char, name: &str| {
+            tiles
+                .into_iter()
+                .find(|(_, &c)| c == tile)
+                .map(|(pos, _)| pos)
+                .ok_or_else(|| AocError::parse(format!("the {} tile `{}` is missing", name, tile)))
+        };
+        let start = find('S', "start")?;
+        let end = find('E', "end")?;
+        let map = Grid::new(tiles.width() as usize, tiles.height() as usize, |x, y| {
+            tiles[(x, y)] != '#'
+        });
+        Ok((map, start, end))
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let path = find_path(&input.0, &input.1, &input.2);
-        format!("{}", count_cheat_possibilities(&path, 2, 100))
+        Ok(count_cheat_possibilities(&path, 2, 100).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
         let path = find_path(&input.0, &input.1, &input.2);
-        format!("{}", count_cheat_possibilities(&path, 20, 100))
+        Ok(count_cheat_possibilities(&path, 20, 100).into())
     }
 }
 
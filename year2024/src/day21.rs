@@ -6,41 +6,56 @@ use super::*;
 
 pub struct Day21;
 impl Solution for Day21 {
-    type Input<'a> = Vec<String>;
+    /// The codes with their numeric part
+    type Input<'a> = Vec<(&'a str, u64)>;
 
-    fn parse<'a>(content: &'a str) -> Self::Input<'a> {
-        content.lines().map(str::to_owned).collect_vec()
+    fn parse<'a>(content: &'a str) -> Result<Self::Input<'a>, AocError> {
+        content
+            .lines()
+            .map(|line| {
+                let digits = line
+                    .strip_suffix('A')
+                    .filter(|digits| {
+                        !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+                    })
+                    .ok_or_else(|| {
+                        AocError::parse_at(content, line, "expected digits followed by `A`")
+                    })?;
+                Ok((line, aoc_core::number(content, digits)?))
+            })
+            .collect()
     }
 
-    fn part_a<'a>(input: &Self::Input<'a>) -> String {
-        format!("{}", solve(&input, 2))
+    fn part_a<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Ok(solve(input, 2).into())
     }
 
-    fn part_b<'a>(input: &Self::Input<'a>) -> String {
-        format!("{}", solve(&input, 25))
+    fn part_b<'a>(input: &Self::Input<'a>) -> Result<Answer, AocError> {
+        Ok(solve(input, 25).into())
     }
 }
 
-fn solve(input: &[String], steps_between: u32) -> u64 {
+fn solve(input: &[(&str, u64)], steps_between: u32) -> u64 {
     let mut mem = HashMap::new();
     input
         .iter()
-        .map(|seq| {
+        .map(|&(seq, value)| {
             let presses: u64 = numbers_to_movement(seq)
                 .map(|(x, restricted)| length(x, false, restricted, steps_between, &mut mem) + 1)
                 .sum();
-            let value: u64 = seq[..(seq.len() - 1)].parse().unwrap();
             presses * value
         })
         .sum()
 }
 
+type LengthCache = HashMap<((i32, i32), bool, bool, u32), u64>;
+
 fn length(
     dir: (i32, i32),
     restricted_top: bool,
     restricted_bottom: bool,
     level: u32,
-    mem: &mut HashMap<((i32, i32), bool, bool, u32), u64>,
+    mem: &mut LengthCache,
 ) -> u64 {
     if let Some(v) = mem.get(&(dir, restricted_top, restricted_bottom, level)) {
         return *v;
@@ -112,7 +127,8 @@ static NUM_PAD: phf::Map<char, (i32, i32)> = phf::phf_map! {
 fn numbers_to_movement<'a>(seq: &'a str) -> impl Iterator<Item = ((i32, i32), bool)> + 'a {
     std::iter::once('A')
         .chain(seq.chars())
-        .map(|c| NUM_PAD.get(&c).unwrap())
+        // `parse` only lets keys of the pad through
+        .filter_map(|c| NUM_PAD.get(&c))
         .tuple_windows()
         .map(|(a, b)| {
             (
@@ -1,113 +1,28 @@
 pub mod parser;
-pub mod utils;
 
-#[derive(Clone, Copy, Debug)]
-enum Part {
-    A,
-    B,
-}
-
-impl Part {
-    fn to_string(&self) -> &'static str {
-        match *self {
-            Part::A => "a",
-            Part::B => "b",
-        }
-    }
-}
-
-pub trait Solution {
-    type Input<'a>;
-
-    fn parse<'a>(content: &'a str) -> Self::Input<'a>;
-
-    fn part_a<'a>(input: &Self::Input<'a>) -> String;
-    fn part_b<'a>(input: &Self::Input<'a>) -> String;
-
-    fn solve(content: &str, part_a: bool, part_b: bool) {
-        let input = Self::parse(content);
-
-        let solve_internal = |part: Part| {
-            println!("");
-            println!("Solving part {} ...", part.to_string());
-            let timer = std::time::Instant::now();
-            let solution = match part {
-                Part::A => Self::part_a(&input),
-                Part::B => Self::part_b(&input),
-            };
-            let duration = timer.elapsed();
-            println!("... took {:?}", duration);
-            println!("");
-            println!("The Solution is:");
-            println!("{solution}");
-            println!("");
-        };
-
-        if part_a {
-            solve_internal(Part::A)
-        }
-        if part_b {
-            solve_internal(Part::B)
-        }
-    }
-}
-
-macro_rules! gen_solve {
-
-    ( $(($x:expr, $m:ident, $d:ident)),* ) => {
-
-        $(mod $m;)*
-
-        pub fn solve(day: u32, part_a: bool, part_b: bool) {
-            let content = std::fs::read_to_string(&format!("input/day{:0>2}.txt", day)).expect("unable to open input file");
-            match day {
-                $($x => {$m::$d::solve(&content, part_a, part_b);} )*
-                _ => {unimplemented!();}
-            }
-        }
-    };
-}
-
-macro_rules! gen_test {
-    (a, $d:ident, $input:expr, $output:expr) => {
-        #[cfg(test)]
-        #[test]
-        fn test_part_a() {
-            let input = $d::parse($input);
-            let output = $output;
-            let result = $d::part_a(&input);
-            assert_eq!(&result, output);
-        }
-    };
-    (b, $d:ident, $input:expr, $output:expr) => {
-        #[test]
-        #[cfg(test)]
-        fn test_part_b() {
-            let input = $d::parse($input);
-            let output = $output;
-            let result = $d::part_b(&input);
-            assert_eq!(&result, output);
-        }
-    };
-}
+use aoc_core::{gen_examples, gen_solve, gen_test, number, Params};
+pub use aoc_core::{utils, Answer, AocError, Solution};
 
 gen_solve!(
-    (01, day01, Day01),
-    (02, day02, Day02),
-    (03, day03, Day03),
-    (04, day04, Day04),
-    (05, day05, Day05),
-    (09, day09, Day09),
-    (10, day10, Day10),
-    (11, day11, Day11),
-    (12, day12, Day12),
-    (13, day13, Day13),
-    (14, day14, Day14),
-    (15, day15, Day15),
-    (16, day16, Day16),
-    (17, day17, Day17),
-    (18, day18, Day18),
-    (19, day19, Day19),
-    (20, day20, Day20),
-    (21, day21, Day21)
+    2024;
+    (1, day01, Day01, "Historian Hysteria"),
+    (2, day02, Day02, "Red-Nosed Reports"),
+    (3, day03, Day03, "Mull It Over"),
+    (4, day04, Day04, "Ceres Search"),
+    (5, day05, Day05, "Print Queue"),
+    (9, day09, Day09, "Disk Fragmenter"),
+    (10, day10, Day10, "Hoof It"),
+    (11, day11, Day11, "Plutonian Pebbles"),
+    (12, day12, Day12, "Garden Groups"),
+    (13, day13, Day13, "Claw Contraption"),
+    (14, day14, Day14, "Restroom Redoubt"),
+    (15, day15, Day15, "Warehouse Woes"),
+    (16, day16, Day16, "Reindeer Maze"),
+    (17, day17, Day17, "Chronospatial Computer"),
+    (18, day18, Day18, "RAM Run"),
+    (19, day19, Day19, "Linen Layout"),
+    (20, day20, Day20, "Race Condition"),
+    (21, day21, Day21, "Keypad Conundrum")
 );
+
+gen_examples!();
@@ -104,10 +104,7 @@ impl<T: Copy, C: Fn(usize, char) -> bool, F: Fn(&str) -> T, const EMPTY: bool> T
         let mut input_chars = input.chars();
         let mut len = 0;
         let mut position = 0;
-        loop {
-            let Some(next) = input_chars.next() else {
-                break;
-            };
+        for next in input_chars.by_ref() {
             if (self.condition)(position, next) {
                 len += next.len_utf8();
                 position += 1;
@@ -155,8 +152,6 @@ pub struct Number<T, I, F: Fn(I) -> T, const SIGNED: bool> {
 
 impl<T: Copy, I: std::str::FromStr, F: Fn(I) -> T, const SIGNED: bool> Tokenizer<T>
     for Number<T, I, F, SIGNED>
-where
-    <I as std::str::FromStr>::Err: std::fmt::Debug,
 {
     fn tokenize<'a>(&self, input: &'a str, output: &mut Vec<T>) -> &'a str {
         let mut input_chars = input.chars().peekable();
@@ -183,11 +178,11 @@ where
             .take_while(|c| c.is_digit(self.radix))
             .map(|c| c.len_utf8())
             .sum::<usize>();
-        output.push((self.finalize)(
-            (&input[..len])
-                .parse::<I>()
-                .expect("Unable to parse number!"),
-        ));
+        // Numbers out of the range of `I` don't match
+        let Ok(number) = input[..len].parse::<I>() else {
+            return input;
+        };
+        output.push((self.finalize)(number));
         &input[len..]
     }
 }
@@ -313,7 +308,7 @@ impl<T: Copy, A: Tokenizer<T>, B: Tokenizer<T>> Tokenizer<T> for Or<T, A, B> {
             return input_b;
         }
         output.truncate(start);
-        return input;
+        input
     }
 }
 
@@ -380,10 +375,10 @@ impl<T, O, C: FnMut(&T) -> bool, F: FnMut(&[T]) -> Option<O>> Parser<T>
     type Output = O;
 
     fn parse<'a>(&mut self, input: &'a [T]) -> (Option<Self::Output>, &'a [T]) {
-        let mut input_iter = input.iter();
+        let input_iter = input.iter();
         let mut counter = 0;
 
-        while let Some(x) = input_iter.next() {
+        for x in input_iter {
             if !(self.condition)(x) {
                 break;
             }
@@ -437,10 +432,10 @@ impl<T, P: Parser<T>> Parser<T> for ParserRepeat<T, P> {
             if let Some(v) = current.take() {
                 result.push(v);
             }
-            if input.len() == 0 {
+            if input.is_empty() {
                 break;
             }
         }
-        return (Some(result), input);
+        (Some(result), input)
     }
 }